@@ -0,0 +1,301 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    alloc,
+    answers::Answers,
+    history::Recorder,
+    isolate::Isolation,
+    output::{self, Format},
+    params, pool,
+    record::{Record, Status},
+    variants::VariantSelector,
+    LAST_DAY,
+};
+
+/// A part to run: part number, whether on the example, and the variant.
+type Job = (u8, bool, Option<String>);
+
+/// How `all` runs and reports.
+pub struct Options<'a> {
+    /// Which inputs to run: `example`, `real` or `both`.
+    pub mode: &'a str,
+    pub variants: &'a VariantSelector,
+    pub jobs: usize,
+    pub format: Format,
+    pub quiet: bool,
+}
+
+/// Run every implemented day and part on the selected inputs and print a
+/// summary. Days without a solution module are listed as skipped.
+///
+/// Returns `true` when no result mismatched its registered answer, panicked,
+/// timed out or (when checked) regressed against the run history.
+pub fn run(
+    year: u32,
+    options: &Options,
+    answers: &Answers,
+    params: &params::Configured,
+    isolation: &Isolation,
+    recorder: &Recorder,
+) -> bool {
+    let modes: &[bool] = match options.mode {
+        "example" => &[true],
+        "real" => &[false],
+        _ => &[true, false],
+    };
+
+    // `None` marks a day without a solution module.
+    let implemented = solvers::implemented_days(year);
+    let mut work: Vec<(u32, Option<Job>)> = Vec::new();
+    for day in 1..=LAST_DAY {
+        if !implemented.contains(&day) {
+            work.push((day, None));
+            continue;
+        }
+        for part in 1..=2u8 {
+            for &use_example in modes {
+                for variant in options.variants.select(year, day, part) {
+                    work.push((day, Some((part, use_example, variant))));
+                }
+            }
+        }
+    }
+
+    let start = Instant::now();
+    let records = pool::run_ordered(&work, options.jobs, |(day, job)| match job {
+        Some((part, use_example, variant)) => {
+            let expected = answers.get(*day, *part, *use_example).map(str::to_string);
+            let input = params.input(year, *day, *use_example).with_variant(variant.clone());
+            Record::run(*part, &input, expected, isolation)
+        }
+        None => Record::skipped(*day),
+    });
+    let wall = start.elapsed();
+    let no_regression = recorder.record(&records);
+
+    if options.quiet {
+        output::print_quiet(&records);
+    } else if options.format == Format::Text {
+        print_table(&records, wall, options.jobs);
+        print_comparison(&records);
+    } else {
+        output::print_records(&records, options.format);
+    }
+
+    no_regression && records.iter().all(|r| !r.status().is_failure())
+}
+
+/// For every day/part/input that ran more than one variant, name the fastest
+/// and flag variants whose answers disagree.
+fn print_comparison(records: &[Record]) {
+    let mut groups: Vec<Vec<&Record>> = Vec::new();
+    for r in records.iter().filter(|r| r.part.is_some()) {
+        match groups.last_mut() {
+            Some(group) if (group[0].day, group[0].part, group[0].mode) == (r.day, r.part, r.mode) => group.push(r),
+            _ => groups.push(vec![r]),
+        }
+    }
+
+    let groups: Vec<Vec<&Record>> = groups.into_iter().filter(|g| g.len() > 1).collect();
+    if groups.is_empty() {
+        return;
+    }
+    println!();
+    println!("Variants:");
+    for group in groups {
+        let name = |r: &Record| r.variant.clone().unwrap_or_else(|| "default".to_string());
+        let label = format!(
+            "Day {:>2} part {} {:<7}",
+            group[0].day,
+            group[0].part.unwrap_or_default(),
+            group[0].mode.unwrap_or("-")
+        );
+
+        let mut answers: Vec<&str> = group.iter().filter_map(|r| r.answer.as_deref()).collect();
+        answers.sort();
+        answers.dedup();
+        if answers.len() > 1 {
+            let listed: Vec<String> = group
+                .iter()
+                .map(|r| format!("{} = {}", name(r), r.answer.as_deref().unwrap_or("-")))
+                .collect();
+            println!("  {}  answers differ: {}", label, listed.join(", "));
+            continue;
+        }
+
+        let mut timed: Vec<(&Record, Duration)> = group
+            .iter()
+            .filter(|r| r.failure.is_none())
+            .filter_map(|r| Some((*r, r.elapsed?)))
+            .collect();
+        timed.sort_by_key(|&(_, elapsed)| elapsed);
+        let [(fastest, best), .., (slowest, worst)] = timed.as_slice() else {
+            continue;
+        };
+        println!(
+            "  {}  {} fastest at {:.2?}, {:.2}x faster than {} at {:.2?}",
+            label,
+            name(fastest),
+            best,
+            worst.as_secs_f64() / best.as_secs_f64().max(f64::EPSILON),
+            name(slowest),
+            worst
+        );
+    }
+}
+
+fn print_table(records: &[Record], wall: Duration, jobs: usize) {
+    let answer_width = records
+        .iter()
+        .filter_map(|r| r.answer.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let with_mem = records.iter().any(|r| r.mem.is_some());
+    // The variant column is only shown when variants ran.
+    let variant_width = records
+        .iter()
+        .filter_map(|r| r.variant.as_ref().map(String::len))
+        .max()
+        .map(|width| width.max("Variant".len()));
+
+    print!("{:>3}  {:>4}  ", "Day", "Part");
+    if let Some(width) = variant_width {
+        print!("{:<width$}  ", "Variant");
+    }
+    print!(
+        "{:<7}  {:<answer_width$}  {:<9}  {:>10}  {:>10}",
+        "Mode", "Answer", "Status", "Time", "CPU"
+    );
+    if with_mem {
+        print!("  {:>10}  {:>11}  {:>11}", "Allocs", "Allocated", "Peak");
+    }
+    println!();
+
+    for r in records {
+        print!("{:>3}  {:>4}  ", r.day, r.part.map_or("-".to_string(), |p| p.to_string()));
+        if let Some(width) = variant_width {
+            let variant = match (&r.variant, r.part) {
+                (Some(variant), _) => variant.as_str(),
+                (None, Some(_)) => "default",
+                (None, None) => "-",
+            };
+            print!("{:<width$}  ", variant);
+        }
+        print!(
+            "{:<7}  {:<answer_width$}  {:<9}  {:>10}  {:>10}",
+            r.mode.unwrap_or("-"),
+            r.answer.as_deref().unwrap_or("-"),
+            r.status().as_str(),
+            r.elapsed
+                .map_or("-".to_string(), |d| format!("{:.2?}{}", d, if r.cached { "*" } else { "" })),
+            r.cpu.map_or("-".to_string(), |d| format!("{:.2?}", d))
+        );
+        match r.mem {
+            Some(m) => print!(
+                "  {:>10}  {:>11}  {:>11}",
+                m.allocations,
+                alloc::format_bytes(m.bytes),
+                alloc::format_bytes(m.peak)
+            ),
+            None if with_mem => print!("  {:>10}  {:>11}  {:>11}", "-", "-", "-"),
+            None => {}
+        }
+        println!();
+    }
+
+    let totals = Totals::of(records);
+    println!();
+    if totals.cached > 0 {
+        println!(
+            "* {} answer(s) from the cache, timed when they were stored ({:.2?} elapsed); not in the times below",
+            totals.cached, totals.cached_elapsed
+        );
+    }
+    println!(
+        "Total: {} solved, {} failed, {} skipped",
+        totals.solved, totals.failed, totals.skipped
+    );
+    println!(
+        "Time: {:.2?} wall on {} job(s); {:.2?} CPU and {:.2?} elapsed summed over jobs ({:.2}x effective parallelism)",
+        wall,
+        jobs,
+        totals.cpu,
+        totals.elapsed,
+        totals.cpu.as_secs_f64() / wall.as_secs_f64().max(f64::EPSILON)
+    );
+}
+
+/// The summary lines of the table.
+#[derive(Debug, Default, PartialEq)]
+struct Totals {
+    solved: usize,
+    failed: usize,
+    skipped: usize,
+    elapsed: Duration,
+    cpu: Duration,
+    /// Cached answers took no time in this run, so they are counted apart.
+    cached: usize,
+    cached_elapsed: Duration,
+}
+
+impl Totals {
+    fn of(records: &[Record]) -> Self {
+        let mut totals = Totals::default();
+        for r in records {
+            match r.status() {
+                Status::Skipped => totals.skipped += 1,
+                Status::Fail => {
+                    totals.solved += 1;
+                    totals.failed += 1;
+                }
+                Status::Panic | Status::Timeout => totals.failed += 1,
+                Status::Pass | Status::Unchecked => totals.solved += 1,
+            }
+            if r.cached {
+                totals.cached += 1;
+                totals.cached_elapsed += r.elapsed.unwrap_or_default();
+            } else {
+                totals.elapsed += r.elapsed.unwrap_or_default();
+                totals.cpu += r.cpu.unwrap_or_default();
+            }
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A solved run of day 1 part 1 that took `ms` of elapsed and CPU time.
+    fn record(ms: u64, cached: bool) -> Record {
+        Record {
+            part: Some(1),
+            mode: Some("real"),
+            answer: Some("3".to_string()),
+            elapsed: Some(Duration::from_millis(ms)),
+            cpu: Some(Duration::from_millis(ms)),
+            cached,
+            ..Record::skipped(1)
+        }
+    }
+
+    #[test]
+    fn cached_answers_are_left_out_of_the_time_totals() {
+        let records = [record(10, false), record(500, true), record(20, false), Record::skipped(2)];
+        assert_eq!(
+            Totals::of(&records),
+            Totals {
+                solved: 3,
+                failed: 0,
+                skipped: 1,
+                elapsed: Duration::from_millis(30),
+                cpu: Duration::from_millis(30),
+                cached: 1,
+                cached_elapsed: Duration::from_millis(500),
+            }
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The system allocator, counting allocations on threads that asked for it.
+struct Counting;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Allocation counters for one tracked run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested over all allocations.
+    pub bytes: u64,
+    /// Highest number of bytes live at once.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    tracking: bool,
+    allocations: u64,
+    bytes: u64,
+    /// Signed, since memory allocated before tracking started may be freed.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Const-initialized and without a destructor, so the allocator can touch
+    // it at any point in a thread's life without allocating itself.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters { tracking: false, allocations: 0, bytes: 0, live: 0, peak: 0 })
+    };
+}
+
+/// Apply `allocated` new and `freed` released bytes to this thread's
+/// counters, if it is tracking.
+fn count(allocated: Option<usize>, freed: usize) {
+    let _ = COUNTERS.try_with(|cell| {
+        let mut c = cell.get();
+        if !c.tracking {
+            return;
+        }
+        if let Some(size) = allocated {
+            c.allocations += 1;
+            c.bytes += size as u64;
+            c.live += size as i64;
+        }
+        c.live -= freed as i64;
+        c.peak = c.peak.max(c.live);
+        cell.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(Some(layout.size()), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(Some(layout.size()), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(None, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(Some(new_size), layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Reset this thread's counters and start counting its allocations.
+pub fn start() {
+    COUNTERS.with(|cell| {
+        cell.set(Counters {
+            tracking: true,
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    });
+}
+
+/// Stop counting this thread's allocations and return the totals since `start`.
+pub fn finish() -> MemStats {
+    COUNTERS.with(|cell| {
+        let mut c = cell.get();
+        c.tracking = false;
+        cell.set(c);
+        MemStats {
+            allocations: c.allocations,
+            bytes: c.bytes,
+            peak: c.peak.max(0) as u64,
+        }
+    })
+}
+
+/// A byte count in binary units, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
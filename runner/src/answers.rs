@@ -0,0 +1,135 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use serde::Deserialize;
+
+use crate::mode_name;
+
+/// Default location of the answer registry, relative to the repository root.
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Known answers for one part, keyed by input kind.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct PartAnswers {
+    example: Option<String>,
+    real: Option<String>,
+}
+
+/// Registry of known answers for one year, loaded from `answers.toml`.
+///
+/// The file is laid out as one table per day and part. Days of years other
+/// than the default one are nested under a `yYYYY` table:
+///
+/// ```toml
+/// [day01.part1]
+/// example = "3"
+/// real = "1040"
+///
+/// [y2024.day01.part1]
+/// example = "11"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    /// `(day, part, use_example) -> answer`
+    entries: BTreeMap<(u32, u8, bool), String>,
+}
+
+impl Answers {
+    /// Load the answers for `year` from `path`. A missing file yields an
+    /// empty registry.
+    pub fn load(path: &Path, year: u32) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        };
+
+        let mut tables: BTreeMap<String, toml::Value> =
+            toml::from_str(&text).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+        // Keep only this year's days, from the top level or its `yYYYY` table.
+        let year_key = format!("y{}", year);
+        let year_days = tables.remove(&year_key);
+        let mut days: BTreeMap<String, toml::Value> = BTreeMap::new();
+        if year == solvers::DEFAULT_YEAR {
+            days.extend(tables.into_iter().filter(|(key, _)| !key.starts_with('y')));
+        }
+        if let Some(value) = year_days {
+            let nested: BTreeMap<String, toml::Value> = value
+                .try_into()
+                .map_err(|e| format!("Invalid [{}] in {}: {}", year_key, path.display(), e))?;
+            days.extend(nested);
+        }
+
+        let mut entries = BTreeMap::new();
+        for (day_key, parts) in days {
+            let parts: BTreeMap<String, PartAnswers> = parts
+                .try_into()
+                .map_err(|e| format!("Invalid [{}] in {}: {}", day_key, path.display(), e))?;
+            let day = parse_key(&day_key, "day")
+                .ok_or_else(|| format!("Invalid day key '{}' in {}", day_key, path.display()))?;
+            for (part_key, answers) in parts {
+                let part = parse_key(&part_key, "part")
+                    .filter(|p| (1..=2).contains(p))
+                    .ok_or_else(|| format!("Invalid part key '{}.{}' in {}", day_key, part_key, path.display()))?;
+                let part = part as u8;
+                if let Some(a) = answers.example {
+                    entries.insert((day, part, true), a);
+                }
+                if let Some(a) = answers.real {
+                    entries.insert((day, part, false), a);
+                }
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Known answer for a day/part on the example or real input.
+    pub fn get(&self, day: u32, part: u8, use_example: bool) -> Option<&str> {
+        self.entries.get(&(day, part, use_example)).map(String::as_str)
+    }
+
+    /// All known answers as `(day, part, use_example, answer)`, in calendar order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u8, bool, &str)> {
+        self.entries
+            .iter()
+            .map(|(&(day, part, use_example), answer)| (day, part, use_example, answer.as_str()))
+    }
+}
+
+/// Append a new `[dayNN.partN]` entry (`[yYYYY.dayNN.partN]` for other
+/// years) to the registry file, creating it if needed. The caller must make
+/// sure the entry does not exist yet.
+pub fn append(path: &Path, year: u32, day: u32, part: u8, use_example: bool, answer: &str) -> Result<(), String> {
+    let prefix = if year == solvers::DEFAULT_YEAR {
+        String::new()
+    } else {
+        format!("y{}.", year)
+    };
+    let entry = format!(
+        "\n[{}day{:02}.part{}]\n{} = {}\n",
+        prefix,
+        day,
+        part,
+        mode_name(use_example),
+        toml::Value::String(answer.to_string())
+    );
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(entry.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Parse keys like `day01` or `part2` into their number.
+pub fn parse_key(key: &str, prefix: &str) -> Option<u32> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
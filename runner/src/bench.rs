@@ -0,0 +1,236 @@
+use std::time::{Duration, Instant};
+
+use solvers::Input;
+
+use crate::{mode_name, params, record::Record, variants::VariantSelector, DaySelector};
+
+/// Summary statistics over a set of timings.
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings collected for one day/part.
+pub struct BenchResult {
+    pub day: u32,
+    pub variant: Option<String>,
+    pub part: u8,
+    pub total: Stats,
+    /// Parse and solve split, for days that expose their parsing step.
+    pub split: Option<(Stats, Stats)>,
+}
+
+/// The median runtime of a solved part on its day's real input, over
+/// `iterations` runs after one warmup. `None` for parts without an answer
+/// and for parts that failed in isolation, which are not benchmarked since
+/// the benchmark runs them in-process.
+pub fn solved_median(year: u32, record: &Record, params: &params::Configured, iterations: u32) -> Option<Duration> {
+    if record.answer.is_none() || record.failure.is_some() {
+        return None;
+    }
+    let input = params.input(year, record.day, false);
+    bench_part(record.part?, &input, 1, iterations).map(|b| b.total.median)
+}
+
+/// Benchmark one part of the day and variant of `input`: `warmup` untimed
+/// runs followed by `iterations` timed runs through the solvers API. Returns
+/// `None` if the day (or the variant's part) is not implemented.
+pub fn bench_part(part: u8, input: &Input, warmup: u32, iterations: u32) -> Option<BenchResult> {
+    for _ in 0..warmup {
+        solvers::run_input(part, input)?;
+    }
+
+    let has_parser = solvers::parse_input(part, input);
+
+    let mut totals = Vec::with_capacity(iterations as usize);
+    let mut parses = Vec::with_capacity(iterations as usize);
+    let mut solves = Vec::with_capacity(iterations as usize);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        solvers::run_input(part, input)?;
+        let total = start.elapsed();
+        totals.push(total);
+
+        if has_parser {
+            let start = Instant::now();
+            solvers::parse_input(part, input);
+            let parse = start.elapsed();
+            parses.push(parse);
+            solves.push(total.saturating_sub(parse));
+        }
+    }
+
+    Some(BenchResult {
+        day: input.day,
+        variant: input.variant.clone(),
+        part,
+        total: Stats::from_samples(&totals),
+        split: has_parser.then(|| (Stats::from_samples(&parses), Stats::from_samples(&solves))),
+    })
+}
+
+/// What `bench` measures and how often.
+pub struct Options<'a> {
+    pub variants: &'a VariantSelector,
+    pub use_example: bool,
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+/// Benchmark the selected days and variants and print a statistics table for
+/// each part, followed by a comparison when several variants were measured.
+pub fn run(year: u32, days: DaySelector, part: Option<u8>, options: &Options, params: &params::Configured) {
+    let Options { variants, use_example, warmup, iterations } = *options;
+    let days: Vec<u32> = match days {
+        DaySelector::All => solvers::implemented_days(year).to_vec(),
+        DaySelector::Day(day) => vec![day],
+    };
+    let parts: Vec<u8> = part.map_or(vec![1, 2], |p| vec![p]);
+
+    println!(
+        "Benchmarking on {} input ({} warmup, {} iterations)",
+        mode_name(use_example),
+        warmup,
+        iterations
+    );
+
+    for day in days {
+        for &part in &parts {
+            let mut results = Vec::new();
+            for variant in variants.select(year, day, part) {
+                println!();
+                let input = params.input(year, day, use_example).with_variant(variant.clone());
+                let Some(result) = bench_part(part, &input, warmup, iterations) else {
+                    println!("Day {} part {}{}: not implemented", day, part, variant_label(variant.as_deref()));
+                    continue;
+                };
+                print_result(&result);
+                results.push(result);
+            }
+            if results.len() > 1 {
+                println!();
+                print_comparison(&mut results);
+            }
+        }
+    }
+}
+
+/// ` [name]` for a variant, empty for the day's main module.
+fn variant_label(variant: Option<&str>) -> String {
+    variant.map_or(String::new(), |v| format!(" [{}]", v))
+}
+
+/// Rank the variants of one day/part by median time.
+fn print_comparison(results: &mut [BenchResult]) {
+    results.sort_by_key(|r| r.total.median);
+    let fastest = results[0].total.median.as_secs_f64().max(f64::EPSILON);
+
+    println!("Day {} part {} variants by median", results[0].day, results[0].part);
+    for (i, r) in results.iter().enumerate() {
+        let relative = if i == 0 {
+            "fastest".to_string()
+        } else {
+            format!("{:.2}x slower", r.total.median.as_secs_f64() / fastest)
+        };
+        println!(
+            "  {:<12} {:>10}  {}",
+            r.variant.as_deref().unwrap_or("default"),
+            format!("{:.2?}", r.total.median),
+            relative
+        );
+    }
+}
+
+fn print_result(result: &BenchResult) {
+    println!("Day {} part {}{}", result.day, result.part, variant_label(result.variant.as_deref()));
+    println!("  {:<6} {:>10} {:>10} {:>10} {:>10}", "", "min", "median", "mean", "stddev");
+    print_stats("total", &result.total);
+    match &result.split {
+        Some((parse, solve)) => {
+            print_stats("parse", parse);
+            print_stats("solve", solve);
+        }
+        None => println!("  (no separate parse step)"),
+    }
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    println!(
+        "  {:<6} {:>10} {:>10} {:>10} {:>10}",
+        label,
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.stddev)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    /// Means and deviations go through `f64`, so allow a nanosecond of error.
+    fn assert_close(actual: Duration, expected: Duration) {
+        assert!(actual.abs_diff(expected) <= Duration::from_nanos(1), "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn odd_sample_count() {
+        let stats = Stats::from_samples(&ms(&[9, 1, 5]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_close(stats.mean, Duration::from_millis(5));
+        // Population deviation of 1, 5, 9: sqrt(32 / 3).
+        assert_close(stats.stddev, Duration::from_secs_f64((32.0f64 / 3.0).sqrt() / 1000.0));
+    }
+
+    #[test]
+    fn even_sample_count_averages_the_middle_two() {
+        let stats = Stats::from_samples(&ms(&[8, 2, 4, 6]));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_close(stats.mean, Duration::from_millis(5));
+        assert_close(stats.stddev, Duration::from_secs_f64(5.0f64.sqrt() / 1000.0));
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::from_samples(&ms(&[7]));
+        assert_eq!(stats.min, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_close(stats.mean, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
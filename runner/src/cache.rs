@@ -0,0 +1,245 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use solvers::{params::metadata_path, Input, Source};
+
+use crate::{hash, mode_name};
+
+/// Default location of the result cache, relative to the repository root.
+pub const DEFAULT_DIR: &str = "cache";
+
+/// A stored answer for one day/part on one input.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    /// The solution variant, or `None` for the day's main module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    pub part: u8,
+    pub mode: String,
+    /// See [`input_key`].
+    pub input_key: String,
+    pub answer: String,
+    /// How long the run that produced the answer took.
+    pub duration_ms: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_ms: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct CacheFile {
+    #[serde(default)]
+    result: Vec<Entry>,
+}
+
+/// Digest of everything a run's answer depends on besides the solver code:
+/// the input text, its parameter overrides and, for an example, its metadata
+/// file. `None` for stdin and unreadable inputs, which are never cached.
+pub fn input_key(input: &Input) -> Option<String> {
+    if input.source == Source::Stdin {
+        return None;
+    }
+    let mut key = input.read().ok()?;
+    for (name, value) in &input.params {
+        key.push_str(&format!("\0{}={}", name, value));
+    }
+    if let Some(path) = input.path().filter(|_| input.example) {
+        if let Ok(metadata) = fs::read_to_string(metadata_path(&path)) {
+            key.push('\0');
+            key.push_str(&metadata);
+        }
+    }
+    Some(hash::hex_digest(&key))
+}
+
+/// Answers of earlier successful runs, stored as `[[result]]` tables in
+/// `<dir>/<fingerprint>.toml`, where the fingerprint is the
+/// [`BUILD_FINGERPRINT`](solvers::BUILD_FINGERPRINT) of the solvers that
+/// produced them.
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    entries: Mutex<Vec<Entry>>,
+}
+
+impl Cache {
+    /// Load the answers stored for the current build from `dir`, deleting
+    /// those of every other build, which can no longer be trusted.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(format!("{}.toml", solvers::BUILD_FINGERPRINT));
+        match fs::read_dir(dir) {
+            Ok(files) => {
+                for file in files.flatten().map(|f| f.path()) {
+                    if file != path && file.extension().is_some_and(|e| e == "toml") {
+                        fs::remove_file(&file).map_err(|e| format!("Failed to remove {}: {}", file.display(), e))?;
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to read {}: {}", dir.display(), e)),
+        }
+
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => {
+                let file: CacheFile = toml::from_str(&text).map_err(|e| {
+                    format!(
+                        "Failed to parse {}: {}; run `cache clear` to start over",
+                        path.display(),
+                        e
+                    )
+                })?;
+                file.result
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        };
+        Ok(Cache {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// The stored answer for `part` of `input`, whose [`input_key`] is `key`.
+    pub fn get(&self, part: u8, input: &Input, key: &str) -> Option<Entry> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .iter()
+            .rev()
+            .find(|e| {
+                e.year == input.year
+                    && e.day == input.day
+                    && e.variant == input.variant
+                    && e.part == part
+                    && e.mode == mode_name(input.example)
+                    && e.input_key == key
+            })
+            .cloned()
+    }
+
+    /// Store the answer of a successful run and append it to the cache file.
+    pub fn insert(
+        &self,
+        part: u8,
+        input: &Input,
+        key: String,
+        answer: String,
+        elapsed: Duration,
+        cpu: Option<Duration>,
+    ) -> Result<(), String> {
+        let entry = Entry {
+            year: input.year,
+            day: input.day,
+            variant: input.variant.clone(),
+            part,
+            mode: mode_name(input.example).to_string(),
+            input_key: key,
+            answer,
+            duration_ms: elapsed.as_secs_f64() * 1000.0,
+            cpu_ms: cpu.map(|d| d.as_secs_f64() * 1000.0),
+        };
+        let text = toml::to_string(&CacheFile {
+            result: vec![entry.clone()],
+        })
+        .map_err(|e| e.to_string())?;
+
+        // Held while writing, so that parallel runs append whole tables.
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut f| write!(f, "\n{}", text))
+            .map_err(|e| format!("Failed to write {}: {}", self.path.display(), e))?;
+        entries.push(entry);
+        Ok(())
+    }
+}
+
+/// Delete every stored answer in `dir`.
+pub fn clear(dir: &Path) -> Result<(), String> {
+    match fs::remove_dir_all(dir) {
+        Ok(()) => {
+            println!("Cleared {}", dir.display());
+            Ok(())
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            println!("{} is already empty", dir.display());
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to remove {}: {}", dir.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::test_support::scratch_dir;
+
+    fn input(text: &str) -> Input {
+        Input::conventional(2025, 1, false).with_source(Source::Text(text.to_string()))
+    }
+
+    fn insert(cache: &Cache, part: u8, input: &Input, answer: &str) {
+        let key = input_key(input).unwrap();
+        cache
+            .insert(part, input, key, answer.to_string(), Duration::from_millis(5), None)
+            .unwrap();
+    }
+
+    fn get(cache: &Cache, part: u8, input: &Input) -> Option<String> {
+        cache.get(part, input, &input_key(input).unwrap()).map(|e| e.answer)
+    }
+
+    #[test]
+    fn loading_discards_other_builds() {
+        let dir = scratch_dir("cache-load");
+        fs::write(dir.join("0123456789abcdef.toml"), "[[result]]\n").unwrap();
+        fs::write(dir.join("notes.txt"), "kept").unwrap();
+        insert(&Cache::load(&dir).unwrap(), 1, &input("L1\n"), "7");
+
+        let cache = Cache::load(&dir).unwrap();
+        assert!(!dir.join("0123456789abcdef.toml").exists());
+        assert!(dir.join("notes.txt").exists());
+        assert_eq!(get(&cache, 1, &input("L1\n")).as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn answers_are_kept_apart_by_everything_they_depend_on() {
+        let cache = Cache::load(&scratch_dir("cache-keys")).unwrap();
+        let base = input("L1\n");
+        insert(&cache, 1, &base, "7");
+        assert_eq!(get(&cache, 1, &base).as_deref(), Some("7"));
+
+        let others = [
+            input("L2\n"),
+            base.clone().with_variant(Some("fast".to_string())),
+            base.clone().with_params(BTreeMap::from([("start".to_string(), 10)])),
+            Input {
+                example: true,
+                ..base.clone()
+            },
+        ];
+        assert_eq!(get(&cache, 2, &base), None);
+        for other in &others {
+            assert_eq!(get(&cache, 1, other), None, "{:?}", other);
+        }
+        // The text and parameters go into the key; the variant and mode are matched on their own.
+        assert_ne!(input_key(&others[0]), input_key(&base));
+        assert_ne!(input_key(&others[2]), input_key(&base));
+        assert_eq!(input_key(&others[1]), input_key(&base));
+
+        assert_eq!(input_key(&base.with_source(Source::Stdin)), None);
+    }
+}
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use crate::{
+    answers::Answers,
+    isolate::Isolation,
+    params, pool,
+    record::{Record, Status},
+    LAST_DAY,
+};
+
+/// Days solved faster than this get a fast badge.
+const FAST: Duration = Duration::from_millis(10);
+
+/// Days slower than this get a slow badge.
+const SLOW: Duration = Duration::from_secs(1);
+
+/// Both parts of one day, run on the real input.
+pub struct DayProgress {
+    pub day: u32,
+    /// One record per part; empty for a day without a solution module.
+    pub parts: Vec<Record>,
+}
+
+impl DayProgress {
+    /// Parts whose real answer matches the registry.
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|r| r.status() == Status::Pass).count()
+    }
+
+    /// One character per part: `★` verified, `☆` solved but not verified,
+    /// `✗` wrong, panicking or timed out, `·` not implemented.
+    pub fn marks(&self) -> String {
+        if self.parts.is_empty() {
+            return "··".to_string();
+        }
+        self.parts
+            .iter()
+            .map(|r| match r.status() {
+                Status::Pass => '★',
+                Status::Unchecked => '☆',
+                Status::Skipped => '·',
+                Status::Fail | Status::Panic | Status::Timeout => '✗',
+            })
+            .collect()
+    }
+
+    /// Total runtime of the parts that produced an answer.
+    pub fn elapsed(&self) -> Option<Duration> {
+        let solved: Vec<Duration> = self
+            .parts
+            .iter()
+            .filter(|r| r.answer.is_some())
+            .filter_map(|r| r.elapsed)
+            .collect();
+        (!solved.is_empty()).then(|| solved.iter().sum())
+    }
+}
+
+/// Run both parts of every implemented day on the real input, checked
+/// against the registry, on `jobs` threads.
+pub fn collect(
+    year: u32,
+    answers: &Answers,
+    params: &params::Configured,
+    isolation: &Isolation,
+    jobs: usize,
+) -> Vec<DayProgress> {
+    let work: Vec<(u32, u8)> = solvers::implemented_days(year)
+        .iter()
+        .flat_map(|&day| [(day, 1), (day, 2)])
+        .collect();
+    let mut records = pool::run_ordered(&work, jobs, |&(day, part)| {
+        Record::run_checked(year, day, part, false, answers, params, isolation)
+    })
+    .into_iter();
+
+    (1..=LAST_DAY)
+        .map(|day| {
+            let parts = if solvers::implemented_days(year).contains(&day) {
+                records.by_ref().take(2).collect()
+            } else {
+                Vec::new()
+            };
+            DayProgress { day, parts }
+        })
+        .collect()
+}
+
+/// A runtime with a badge for notably fast or slow days.
+pub fn badge(elapsed: Duration) -> String {
+    let icon = if elapsed < FAST {
+        "⚡"
+    } else if elapsed > SLOW {
+        "🐢"
+    } else {
+        "  "
+    };
+    format!("{} {:.2?}", icon, elapsed)
+}
+
+/// Print an Advent-style calendar with a star per verified part and a
+/// runtime badge per day.
+pub fn run(year: u32, answers: &Answers, params: &params::Configured, isolation: &Isolation, jobs: usize) {
+    let progress = collect(year, answers, params, isolation, jobs);
+    let title_width = progress
+        .iter()
+        .filter_map(|p| solvers::title(year, p.day).map(str::len))
+        .max()
+        .unwrap_or(0);
+
+    println!("Advent of Code {}", year);
+    println!();
+    for p in &progress {
+        let line = format!(
+            "{:>3}  {}  {:<title_width$}  {}",
+            p.day,
+            p.marks(),
+            solvers::title(year, p.day).unwrap_or(""),
+            p.elapsed().map(badge).unwrap_or_default()
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let stars: usize = progress.iter().map(DayProgress::stars).sum();
+    println!();
+    println!("{}/{} ★", stars, LAST_DAY * 2);
+    println!("★ verified  ☆ unverified  ✗ failing  ⚡ under {:.0?}  🐢 over {:.0?}", FAST, SLOW);
+}
@@ -0,0 +1,227 @@
+use std::{
+    fs,
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+use ureq::Agent;
+
+/// Default Advent of Code site; overridable so tests can use a local server.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// File holding the session token when `AOC_SESSION` is not set.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = "github.com/g-hurst/adventOfCode2025 runner";
+
+/// Minimal Advent of Code HTTP client that spaces its requests at least
+/// `min_interval` apart.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    year: u32,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, year: u32, min_interval: Duration) -> Self {
+        let agent: Agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            year,
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    /// The puzzle year requests are made for.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Sleep until `min_interval` has passed since the previous request.
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            let since = last.elapsed();
+            if since < self.min_interval {
+                thread::sleep(self.min_interval - since);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Download the puzzle input for `day`. Returns `Ok(None)` if the day is
+    /// not available (yet).
+    pub fn input(&mut self, day: u32) -> Result<Option<String>, String> {
+        self.throttle();
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, self.year, day);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", self.cookie())
+            .call()
+            .map_err(|e| format!("GET {} failed: {}", url, e))?;
+
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("Failed to read response from {}: {}", url, e))?;
+
+        match status {
+            200 => Ok(Some(body)),
+            404 => Ok(None),
+            _ => Err(format!("GET {} returned {}: {}", url, status, body.trim())),
+        }
+    }
+
+    /// Submit `answer` for a day/part and return the response page.
+    pub fn submit(&mut self, day: u32, part: u8, answer: &str) -> Result<String, String> {
+        self.throttle();
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, self.year, day);
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|e| format!("POST {} failed: {}", url, e))?;
+
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("Failed to read response from {}: {}", url, e))?;
+
+        match status {
+            200 => Ok(body),
+            _ => Err(format!("POST {} returned {}: {}", url, status, body.trim())),
+        }
+    }
+}
+
+/// Resolve the session token: the given value (from `--session` or
+/// `AOC_SESSION`) wins, otherwise the first line of `session_file`.
+pub fn session_token(given: Option<String>, session_file: &Path) -> Result<String, String> {
+    if let Some(token) = given.filter(|t| !t.trim().is_empty()) {
+        return Ok(token.trim().to_string());
+    }
+
+    let path = session_file;
+    let token = fs::read_to_string(path).map_err(|_| {
+        format!(
+            "No session token: set AOC_SESSION or put it in {}",
+            path.display()
+        )
+    })?;
+    let token = token.lines().next().unwrap_or("").trim().to_string();
+    if token.is_empty() {
+        return Err(format!("{} is empty", path.display()));
+    }
+    Ok(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::serve;
+
+    fn client(url: &str) -> Client {
+        Client::new(url, "secret".to_string(), 2025, Duration::ZERO)
+    }
+
+    #[test]
+    fn input_downloads_with_the_session_cookie() {
+        let (url, server) = serve(vec![(200, "1\n2\n")]);
+        assert_eq!(client(&url).input(3), Ok(Some("1\n2\n".to_string())));
+
+        let received = server.join().unwrap();
+        assert_eq!(received[0].method, "GET");
+        assert_eq!(received[0].url, "/2025/day/3/input");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=secret"));
+    }
+
+    #[test]
+    fn input_not_available_yet() {
+        let (url, server) = serve(vec![(404, "Please don't repeatedly request this endpoint")]);
+        assert_eq!(client(&url).input(25), Ok(None));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn input_errors_carry_the_status() {
+        let (url, server) = serve(vec![(400, "Puzzle inputs differ by user. Please log in.")]);
+        let error = client(&url).input(1).unwrap_err();
+        assert!(error.contains("returned 400"), "{}", error);
+        assert!(error.contains("Please log in"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submit_posts_the_level_and_answer() {
+        let (url, server) = serve(vec![(200, "<p>That's the right answer!</p>")]);
+        let body = client(&url).submit(4, 2, "1234").unwrap();
+        assert!(body.contains("right answer"));
+
+        let received = server.join().unwrap();
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(received[0].url, "/2025/day/4/answer");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=secret"));
+        assert_eq!(received[0].body, "level=2&answer=1234");
+    }
+
+    #[test]
+    fn submit_errors_carry_the_status() {
+        let (url, server) = serve(vec![(500, "Internal Server Error")]);
+        let error = client(&url).submit(1, 1, "3").unwrap_err();
+        assert!(error.contains("returned 500"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn unreachable_site_is_an_error() {
+        // Bind and drop a listener to get a port nobody listens on.
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let error = client(&format!("http://127.0.0.1:{}", port)).input(1).unwrap_err();
+        assert!(error.starts_with("GET "), "{}", error);
+    }
+
+    #[test]
+    fn requests_are_spaced_by_the_minimum_interval() {
+        let (url, server) = serve(vec![(200, "a"), (200, "b")]);
+        let interval = Duration::from_millis(200);
+        let mut client = Client::new(&url, "secret".to_string(), 2025, interval);
+        let start = Instant::now();
+        client.input(1).unwrap();
+        client.input(2).unwrap();
+        assert!(start.elapsed() >= interval);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn session_token_prefers_the_given_value() {
+        let dir = crate::test_support::scratch_dir("session");
+        let file = dir.join(SESSION_FILE);
+        fs::write(&file, "from-file\nignored\n").unwrap();
+        assert_eq!(session_token(Some(" given ".to_string()), &file), Ok("given".to_string()));
+        assert_eq!(session_token(None, &file), Ok("from-file".to_string()));
+        assert!(session_token(None, &dir.join("missing")).is_err());
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::answers::parse_key;
+
+/// Name of the project configuration file; its directory is the repository root.
+pub const FILE_NAME: &str = "aoc.toml";
+
+/// Settings that can also be given for a single day, as a `[dayNN]` table.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DayConfig {
+    /// Input used when a single run does not name one: `example` or `real`.
+    pub mode: Option<String>,
+    /// Solver timeout in seconds; 0 waits forever.
+    pub timeout: Option<f64>,
+    /// Values for the day's tunable parameters on real inputs.
+    #[serde(default)]
+    pub params: BTreeMap<String, i64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct ConfigFile {
+    inputs_dir: Option<PathBuf>,
+    year: Option<u32>,
+    mode: Option<String>,
+    timeout: Option<f64>,
+    gate: Option<bool>,
+    cache: Option<bool>,
+    /// `[dayNN]` tables; anything else is rejected after parsing.
+    #[serde(flatten)]
+    days: BTreeMap<String, toml::Value>,
+}
+
+/// Project configuration from `aoc.toml`, with `AOC_*` environment
+/// variables applied on top.
+///
+/// ```toml
+/// inputs_dir = "inputs"
+/// mode = "example"
+/// year = 2025
+/// timeout = 60
+/// gate = false
+/// cache = false
+///
+/// [day08]
+/// timeout = 120
+/// params = { connections = 1000 }
+/// ```
+#[derive(Debug)]
+pub struct Config {
+    /// The repository root; every default path is resolved against it.
+    pub root: PathBuf,
+    pub inputs_dir: PathBuf,
+    pub year: u32,
+    /// Check the example answer before running a real input; off unless
+    /// turned on, since it doubles the work of every real run.
+    pub gate: bool,
+    /// Reuse stored answers for unchanged solvers and inputs.
+    pub cache: bool,
+    defaults: DayConfig,
+    days: BTreeMap<u32, DayConfig>,
+}
+
+impl Config {
+    /// Find the repository root and load its configuration.
+    ///
+    /// The root is `AOC_ROOT` if set, otherwise the nearest directory at or
+    /// above the current one that contains `aoc.toml`, otherwise the current
+    /// directory. `AOC_INPUTS_DIR`, `AOC_MODE`, `AOC_YEAR`, `AOC_TIMEOUT`,
+    /// `AOC_GATE` and `AOC_CACHE` override the file, including its per-day
+    /// tables.
+    pub fn discover() -> Result<Self, String> {
+        let cwd = env::current_dir().map_err(|e| format!("Failed to read the current directory: {}", e))?;
+        Config::discover_from(&cwd)
+    }
+
+    /// [`discover`](Config::discover), starting from `cwd`.
+    fn discover_from(cwd: &Path) -> Result<Self, String> {
+        let root = match env::var_os("AOC_ROOT") {
+            Some(root) => cwd.join(root),
+            None => cwd
+                .ancestors()
+                .find(|dir| dir.join(FILE_NAME).is_file())
+                .unwrap_or(cwd)
+                .to_path_buf(),
+        };
+
+        let path = root.join(FILE_NAME);
+        let name = path.display().to_string();
+        let config: ConfigFile = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("Failed to parse {}: {}", name, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => ConfigFile::default(),
+            Err(e) => return Err(format!("Failed to read {}: {}", name, e)),
+        };
+
+        let mut days = BTreeMap::new();
+        for (key, value) in config.days {
+            let day = parse_key(&key, "day").ok_or_else(|| format!("Unknown key '{}' in {}", key, name))?;
+            let day_config: DayConfig = value
+                .try_into()
+                .map_err(|e| format!("Invalid [{}] in {}: {}", key, name, e))?;
+            days.insert(day, day_config);
+        }
+
+        let mut config = Config {
+            inputs_dir: root.join(config.inputs_dir.unwrap_or_else(|| PathBuf::from("inputs"))),
+            year: config.year.unwrap_or(solvers::DEFAULT_YEAR),
+            gate: config.gate.unwrap_or(false),
+            cache: config.cache.unwrap_or(false),
+            defaults: DayConfig {
+                mode: config.mode,
+                timeout: config.timeout,
+                params: BTreeMap::new(),
+            },
+            days,
+            root,
+        };
+        config.apply_env()?;
+        config.validate(&name)?;
+        Ok(config)
+    }
+
+    fn apply_env(&mut self) -> Result<(), String> {
+        if let Some(dir) = env::var_os("AOC_INPUTS_DIR") {
+            self.inputs_dir = self.root.join(dir);
+        }
+        if let Ok(year) = env::var("AOC_YEAR") {
+            self.year = year.parse().map_err(|_| format!("AOC_YEAR: invalid year '{}'", year))?;
+        }
+        if let Ok(mode) = env::var("AOC_MODE") {
+            self.defaults.mode = Some(mode);
+            self.days.values_mut().for_each(|d| d.mode = None);
+        }
+        if let Ok(timeout) = env::var("AOC_TIMEOUT") {
+            let timeout = timeout
+                .parse()
+                .map_err(|_| format!("AOC_TIMEOUT: invalid number of seconds '{}'", timeout))?;
+            self.defaults.timeout = Some(timeout);
+            self.days.values_mut().for_each(|d| d.timeout = None);
+        }
+        if let Ok(gate) = env::var("AOC_GATE") {
+            self.gate = gate
+                .parse()
+                .map_err(|_| format!("AOC_GATE: expected true or false, got '{}'", gate))?;
+        }
+        if let Ok(cache) = env::var("AOC_CACHE") {
+            self.cache = cache
+                .parse()
+                .map_err(|_| format!("AOC_CACHE: expected true or false, got '{}'", cache))?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self, name: &str) -> Result<(), String> {
+        let all = std::iter::once(&self.defaults).chain(self.days.values());
+        for day in all {
+            if let Some(mode) = day.mode.as_deref().filter(|m| !matches!(*m, "example" | "real")) {
+                return Err(format!("Invalid mode '{}' in {}, expected example or real", mode, name));
+            }
+            if let Some(timeout) = day.timeout.filter(|t| !(t.is_finite() && *t >= 0.0)) {
+                return Err(format!("Invalid timeout {} in {}", timeout, name));
+            }
+        }
+        Ok(())
+    }
+
+    /// `path` relative to the repository root; absolute paths are kept.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// The default input for `day`, if configured.
+    pub fn mode(&self, day: u32) -> Option<&str> {
+        self.days
+            .get(&day)
+            .and_then(|d| d.mode.as_deref())
+            .or(self.defaults.mode.as_deref())
+    }
+
+    /// The configured solver timeout in seconds, if any.
+    pub fn timeout(&self) -> Option<f64> {
+        self.defaults.timeout
+    }
+
+    /// Parameter values configured for `day`'s real input.
+    pub fn params(&self, day: u32) -> BTreeMap<String, i64> {
+        self.days.get(&day).map(|d| d.params.clone()).unwrap_or_default()
+    }
+
+    /// Days with a `params` table.
+    pub fn param_days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.iter().filter(|(_, d)| !d.params.is_empty()).map(|(&day, _)| day)
+    }
+
+    /// Days with their own timeout, in seconds.
+    pub fn day_timeouts(&self) -> impl Iterator<Item = (u32, f64)> + '_ {
+        self.days.iter().filter_map(|(&day, d)| Some((day, d.timeout?)))
+    }
+}
+
+/// A timeout in seconds as used by the isolation layer: 0 waits forever.
+pub fn timeout_duration(seconds: f64) -> Option<Duration> {
+    (seconds > 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, PoisonError};
+
+    use super::*;
+    use crate::test_support::scratch_dir;
+
+    /// Every variable `discover` reads.
+    const VARS: &[&str] = &[
+        "AOC_ROOT",
+        "AOC_INPUTS_DIR",
+        "AOC_MODE",
+        "AOC_YEAR",
+        "AOC_TIMEOUT",
+        "AOC_GATE",
+        "AOC_CACHE",
+    ];
+
+    /// Discover the configuration from `cwd` with exactly `vars` set among
+    /// the `AOC_*` variables. The environment is process-wide, so callers
+    /// take turns.
+    fn discover(cwd: &Path, vars: &[(&str, &str)]) -> Result<Config, String> {
+        static ENV: Mutex<()> = Mutex::new(());
+        let _turn = ENV.lock().unwrap_or_else(PoisonError::into_inner);
+        VARS.iter().for_each(|name| env::remove_var(name));
+        vars.iter().for_each(|(name, value)| env::set_var(name, value));
+        let config = Config::discover_from(cwd);
+        VARS.iter().for_each(|name| env::remove_var(name));
+        config
+    }
+
+    /// A scratch repository with `aoc.toml` holding `text`.
+    fn repo(name: &str, text: &str) -> PathBuf {
+        let root = scratch_dir(name);
+        fs::write(root.join(FILE_NAME), text).unwrap();
+        root
+    }
+
+    #[test]
+    fn the_root_is_the_nearest_directory_with_a_config() {
+        let root = repo("config-walk", "timeout = 7\n");
+        let cwd = root.join("solvers").join("src");
+        fs::create_dir_all(&cwd).unwrap();
+
+        let config = discover(&cwd, &[]).unwrap();
+        assert_eq!(config.root, root);
+        assert_eq!(config.inputs_dir, root.join("inputs"));
+        assert_eq!(config.timeout(), Some(7.0));
+    }
+
+    #[test]
+    fn aoc_root_overrides_the_search() {
+        let nearest = repo("config-nearest", "timeout = 7\n");
+        let other = repo("config-other", "timeout = 3\n");
+
+        let config = discover(&nearest, &[("AOC_ROOT", other.to_str().unwrap())]).unwrap();
+        assert_eq!(config.root, other);
+        assert_eq!(config.timeout(), Some(3.0));
+
+        // A relative root is taken from the current directory.
+        let relative = Path::new("..").join(other.file_name().unwrap());
+        let config = discover(&nearest, &[("AOC_ROOT", relative.to_str().unwrap())]).unwrap();
+        assert_eq!(config.timeout(), Some(3.0));
+    }
+
+    #[test]
+    fn environment_variables_beat_the_file() {
+        let text = "inputs_dir = \"data\"\nmode = \"example\"\ntimeout = 60\ngate = false\n\n\
+                    [day08]\nmode = \"example\"\ntimeout = 120\n";
+        let root = repo("config-env", text);
+
+        let config = discover(&root, &[]).unwrap();
+        assert_eq!((config.mode(1), config.mode(8)), (Some("example"), Some("example")));
+        assert_eq!(config.day_timeouts().collect::<Vec<_>>(), [(8, 120.0)]);
+
+        let vars = [
+            ("AOC_INPUTS_DIR", "elsewhere"),
+            ("AOC_MODE", "real"),
+            ("AOC_YEAR", "2024"),
+            ("AOC_TIMEOUT", "5"),
+            ("AOC_GATE", "true"),
+        ];
+        let config = discover(&root, &vars).unwrap();
+        assert_eq!(config.inputs_dir, root.join("elsewhere"));
+        assert_eq!(config.year, 2024);
+        assert!(config.gate);
+        // Including the per-day tables.
+        assert_eq!((config.mode(1), config.mode(8)), (Some("real"), Some("real")));
+        assert_eq!(config.timeout(), Some(5.0));
+        assert_eq!(config.day_timeouts().count(), 0);
+
+        let e = discover(&root, &[("AOC_GATE", "yes")]).unwrap_err();
+        assert_eq!(e, "AOC_GATE: expected true or false, got 'yes'");
+    }
+}
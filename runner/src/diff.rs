@@ -0,0 +1,245 @@
+use std::{fs, path::PathBuf};
+
+use solvers::{Input, Source};
+
+use crate::{
+    isolate::{self, Isolation},
+    variants,
+};
+
+/// Name of a day's main module where a variant name is expected.
+pub const MAIN_VARIANT: &str = "default";
+
+/// Where the minimized input is written unless `--out` is given: next to
+/// the day's example, as `dayNN_example_candidate.txt`.
+pub fn candidate_path(year: u32, day: u32) -> PathBuf {
+    solvers::input::conventional_path(year, day, true).with_file_name(format!("day{:02}_example_candidate.txt", day))
+}
+
+/// Two variants of one day/part, run side by side on candidate inputs.
+struct Pair<'a> {
+    part: u8,
+    input: &'a Input,
+    a: Option<String>,
+    b: Option<String>,
+    isolation: &'a Isolation,
+    runs: usize,
+}
+
+impl Pair<'_> {
+    /// The answer of `variant` on `text`, or why it has none.
+    fn solve(&mut self, variant: &Option<String>, text: &str) -> Result<String, String> {
+        self.runs += 1;
+        let input = self
+            .input
+            .clone()
+            .with_source(Source::Text(text.to_string()))
+            .with_variant(variant.clone());
+        match isolate::run(self.part, &input, self.isolation) {
+            Ok(Some(solved)) => Ok(solved.answer),
+            Ok(None) => Err("not implemented".to_string()),
+            Err(failure) => Err(failure.to_string()),
+        }
+    }
+
+    /// Both answers on `text`, if both variants solved it and they differ.
+    fn disagreement(&mut self, text: &str) -> Option<(String, String)> {
+        let a = self.solve(&self.a.clone(), text).ok()?;
+        let b = self.solve(&self.b.clone(), text).ok()?;
+        (a != b).then_some((a, b))
+    }
+}
+
+/// Run variants `a` and `b` of a day/part on `input` and, if they give
+/// different answers, shrink the input line by line to a minimal one on
+/// which they still do, and write it to `out`.
+///
+/// Runs that panic or time out during shrinking count as agreeing, so the
+/// result is always an input that both variants solve.
+pub fn run(
+    part: u8,
+    a: &str,
+    b: &str,
+    input: &Input,
+    out: Option<PathBuf>,
+    isolation: &Isolation,
+) -> Result<(), String> {
+    let (year, day) = (input.year, input.day);
+    if a == b {
+        return Err(format!("--a and --b are both '{}'; nothing to compare", a));
+    }
+    let variant = |name: &str| -> Result<Option<String>, String> {
+        if name == MAIN_VARIANT {
+            return Ok(None);
+        }
+        variants::check(year, day, Some(part), name)?;
+        Ok(Some(name.to_string()))
+    };
+
+    let mut pair = Pair {
+        part,
+        input,
+        a: variant(a)?,
+        b: variant(b)?,
+        isolation,
+        runs: 0,
+    };
+
+    let text = input.read().map_err(|e| format!("Failed to read {}: {}", input, e))?;
+    let lines: Vec<&str> = text.lines().collect();
+    let answer_a = pair.solve(&pair.a.clone(), &text).map_err(|e| format!("{} {} on {}", a, e, input))?;
+    let answer_b = pair.solve(&pair.b.clone(), &text).map_err(|e| format!("{} {} on {}", b, e, input))?;
+    if answer_a == answer_b {
+        println!("{} and {} agree on {}: {}", a, b, input, answer_a);
+        return Ok(());
+    }
+
+    println!("Day {} part {}: {} and {} disagree on {} ({} lines)", day, part, a, b, input, lines.len());
+    println!("  {:<12}  {}", a, answer_a);
+    println!("  {:<12}  {}", b, answer_b);
+    println!();
+
+    let newline = if text.ends_with('\n') { "\n" } else { "" };
+    let join = |lines: &[&str]| format!("{}{}", lines.join("\n"), newline);
+    // `minimize` keeps every candidate that still disagrees, so each one is progress.
+    let minimal = minimize(lines, |candidate| {
+        let disagrees = pair.disagreement(&join(candidate)).is_some();
+        if disagrees {
+            println!("  {} line(s)", candidate.len());
+        }
+        disagrees
+    });
+    let minimal = join(&minimal);
+    let (answer_a, answer_b) = pair
+        .disagreement(&minimal)
+        .expect("the minimized input was tested to disagree");
+
+    println!();
+    println!("Minimal input: {} line(s) after {} runs", minimal.lines().count(), pair.runs);
+    println!("  {:<12}  {}", a, answer_a);
+    println!("  {:<12}  {}", b, answer_b);
+
+    let out = out.unwrap_or_else(|| candidate_path(year, day));
+    if let Some(dir) = out.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(&out, &minimal).map_err(|e| format!("Failed to write {}: {}", out.display(), e))?;
+    println!("Wrote {}; check it by hand before making it an example", out.display());
+    Ok(())
+}
+
+/// Delta debugging over lines: the smallest subset of `lines`, in order,
+/// found by repeatedly removing chunks while `fails` keeps holding. The
+/// result is 1-minimal: removing any single line makes `fails` false.
+fn minimize<'a>(mut lines: Vec<&'a str>, mut fails: impl FnMut(&[&'a str]) -> bool) -> Vec<&'a str> {
+    let mut chunks = 2;
+    while lines.len() >= 2 {
+        let size = lines.len().div_ceil(chunks);
+        let starts: Vec<usize> = (0..lines.len()).step_by(size).collect();
+
+        // First try to keep a single chunk, then to drop one.
+        let subset = starts
+            .iter()
+            .map(|&start| lines[start..(start + size).min(lines.len())].to_vec())
+            .find(|chunk| fails(chunk));
+        if let Some(chunk) = subset {
+            lines = chunk;
+            chunks = 2;
+            continue;
+        }
+        // With two chunks, dropping one is the same as keeping the other.
+        let complement = (chunks > 2)
+            .then(|| {
+                starts
+                    .iter()
+                    .map(|&start| [&lines[..start], &lines[(start + size).min(lines.len())..]].concat())
+                    .find(|rest| fails(rest))
+            })
+            .flatten();
+        if let Some(rest) = complement {
+            lines = rest;
+            chunks = (chunks - 1).max(2);
+            continue;
+        }
+
+        if chunks >= lines.len() {
+            break;
+        }
+        chunks = (chunks * 2).min(lines.len());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `minimize` on `lines`, checking that the predicate still holds on
+    /// the result, that the result is 1-minimal (a single line is never
+    /// dropped), and that the search did not take more than a quadratic
+    /// number of tests.
+    fn check<'a>(lines: &[&'a str], fails: impl Fn(&[&str]) -> bool) -> Vec<&'a str> {
+        let mut calls = 0;
+        let minimal = minimize(lines.to_vec(), |subset| {
+            calls += 1;
+            fails(subset)
+        });
+        assert!(fails(&minimal), "{:?} no longer fails", minimal);
+        if minimal.len() > 1 {
+            for i in 0..minimal.len() {
+                let rest = [&minimal[..i], &minimal[i + 1..]].concat();
+                assert!(!fails(&rest), "{:?} still fails without line {}", minimal, i);
+            }
+        }
+        assert!(calls <= lines.len().pow(2), "{} tests for {} line(s)", calls, lines.len());
+        minimal
+    }
+
+    fn numbered(n: usize) -> Vec<String> {
+        (0..n).map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn finds_the_lines_that_matter_in_order() {
+        let lines = numbered(64);
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let fails = |subset: &[&str]| subset.contains(&"50") && subset.contains(&"7") && subset.contains(&"33");
+        assert_eq!(check(&lines, fails), ["7", "33", "50"]);
+    }
+
+    #[test]
+    fn single_culprit() {
+        let lines = numbered(100);
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        assert_eq!(check(&lines, |subset| subset.contains(&"99")), ["99"]);
+    }
+
+    #[test]
+    fn interchangeable_lines() {
+        // Any three lines fail; which ones is up to the search.
+        let lines = numbered(37);
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        assert_eq!(check(&lines, |subset| subset.len() >= 3).len(), 3);
+    }
+
+    #[test]
+    fn order_dependent_predicate() {
+        // Fails while some "b" comes after some "a".
+        let lines = ["x", "b", "a", "x", "x", "b", "a", "x", "b"];
+        let fails = |subset: &[&str]| {
+            subset
+                .iter()
+                .position(|&l| l == "a")
+                .is_some_and(|a| subset[a..].contains(&"b"))
+        };
+        assert_eq!(check(&lines, fails), ["a", "b"]);
+    }
+
+    #[test]
+    fn already_minimal_inputs_are_kept() {
+        let lines = ["a", "b", "c"];
+        assert_eq!(check(&lines, |subset| subset.len() == 3), ["a", "b", "c"]);
+        assert_eq!(check(&["a"], |_| true), ["a"]);
+        assert_eq!(check(&[], |_| true), Vec::<&str>::new());
+    }
+}
@@ -0,0 +1,84 @@
+use std::fs;
+
+use crate::{client::Client, DaySelector, LAST_DAY};
+
+/// Download missing real inputs into `inputs/`.
+///
+/// An input that already exists and is non-empty is never downloaded again
+/// (the empty files written by `runner new` count as missing). With `all`,
+/// fetching stops at the first day that is not available yet.
+pub fn run(days: DaySelector, client: &mut Client) -> Result<(), String> {
+    let (days, stop_at_unavailable): (Vec<u32>, bool) = match days {
+        DaySelector::All => ((1..=LAST_DAY).collect(), true),
+        DaySelector::Day(day) => (vec![day], false),
+    };
+
+    for day in days {
+        let path = solvers::input::conventional_path(client.year(), day, false);
+        if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+            println!("Day {:>2}: cached at {}", day, path.display());
+            continue;
+        }
+
+        match client.input(day)? {
+            Some(text) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+                }
+                fs::write(&path, text).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+                println!("Day {:>2}: downloaded to {}", day, path.display());
+            }
+            None if stop_at_unavailable => {
+                println!("Day {:>2}: not available yet, stopping", day);
+                break;
+            }
+            None => return Err(format!("Day {} is not available yet", day)),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{inputs_dir, serve};
+    use std::time::Duration;
+
+    fn client(url: &str) -> Client {
+        Client::new(url, "secret".to_string(), solvers::DEFAULT_YEAR, Duration::ZERO)
+    }
+
+    #[test]
+    fn downloads_missing_inputs_and_keeps_existing_ones() {
+        let dir = inputs_dir();
+        let (url, server) = serve(vec![(200, "fetched\n")]);
+        // Day 1 is already there, so only day 11 is requested.
+        run(DaySelector::Day(1), &mut client(&url)).unwrap();
+        run(DaySelector::Day(11), &mut client(&url)).unwrap();
+
+        let received = server.join().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].url, "/2025/day/11/input");
+        assert_eq!(fs::read_to_string(dir.join("day11.txt")).unwrap(), "fetched\n");
+        assert!(fs::read_to_string(dir.join("day01.txt")).unwrap().starts_with("L68"));
+    }
+
+    #[test]
+    fn unavailable_day_is_an_error() {
+        inputs_dir();
+        let (url, server) = serve(vec![(404, "Not Found")]);
+        let error = run(DaySelector::Day(12), &mut client(&url)).unwrap_err();
+        assert_eq!(error, "Day 12 is not available yet");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn server_errors_stop_the_fetch() {
+        inputs_dir();
+        let (url, server) = serve(vec![(500, "Internal Server Error")]);
+        let error = run(DaySelector::Day(13), &mut client(&url)).unwrap_err();
+        assert!(error.contains("returned 500"), "{}", error);
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,59 @@
+use solvers::Input;
+
+use crate::{
+    answers::Answers,
+    isolate::Isolation,
+    record::{Record, Status},
+};
+
+/// Run the example of a day/part before `real`, with the same solution
+/// variant, so that a wrong solution does not produce a plausible-looking
+/// real answer.
+///
+/// Passes when no example answer is registered. Otherwise returns a report
+/// with the expected and actual example answers if they differ, or the
+/// failure if the example panicked or timed out.
+pub fn check(part: u8, real: &Input, answers: &Answers, isolation: &Isolation) -> Result<(), String> {
+    let day = real.day;
+    let Some(expected) = answers.get(day, part, true) else {
+        return Ok(());
+    };
+    let example = Input::conventional(real.year, day, true).with_variant(real.variant.clone());
+    let record = Record::run(part, &example, Some(expected.to_string()), isolation);
+
+    let mut report = format!("🚧 Day {} part {} fails on the example, so the real input was not run:\n", day, part);
+    match record.status() {
+        Status::Pass | Status::Skipped | Status::Unchecked => return Ok(()),
+        Status::Fail => report.push_str(&diff(expected, record.answer.as_deref().unwrap_or_default())),
+        Status::Panic | Status::Timeout => {
+            let failure = record.failure.as_ref().map_or(String::new(), ToString::to_string);
+            report.push_str(&format!("   the example {}\n", failure));
+        }
+    }
+    report.push_str("   Fix the example first, or pass --no-gate to run the real input anyway.");
+    Err(report)
+}
+
+/// Expected and actual answers, line by line for multi-line answers.
+fn diff(expected: &str, got: &str) -> String {
+    if !expected.contains('\n') && !got.contains('\n') {
+        return format!("   expected  {}\n   got       {}\n", expected, got);
+    }
+
+    let mut out = String::from("   (- expected, + got)\n");
+    let (expected, got): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), got.lines().collect());
+    for i in 0..expected.len().max(got.len()) {
+        match (expected.get(i), got.get(i)) {
+            (Some(e), Some(g)) if e == g => out.push_str(&format!("     {}\n", e)),
+            (e, g) => {
+                if let Some(e) = e {
+                    out.push_str(&format!("   - {}\n", e));
+                }
+                if let Some(g) = g {
+                    out.push_str(&format!("   + {}\n", g));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,267 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Default location of the submission log, relative to the repository root.
+pub const DEFAULT_PATH: &str = "guesses.toml";
+
+/// The site's verdict on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction.
+    Wrong,
+    /// Submitted too soon after a previous wrong answer; says nothing about the answer.
+    RateLimited,
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    /// A response we could not classify.
+    Unknown,
+}
+
+impl Verdict {
+    /// Classify the HTML returned by the answer endpoint.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate limited",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    /// Whether this verdict proves the answer wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// One submitted answer and its verdict.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct GuessFile {
+    #[serde(default)]
+    guess: Vec<Guess>,
+}
+
+/// The local log of every submitted answer, stored as `[[guess]]` tables.
+#[derive(Debug, Default)]
+pub struct GuessLog {
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// Load the guesses for `year` from `path`. A missing file yields an
+    /// empty log.
+    pub fn load(path: &Path, year: u32) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        };
+        let file: GuessFile =
+            toml::from_str(&text).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        let guesses = file.guess.into_iter().filter(|g| g.year == year).collect();
+        Ok(GuessLog { guesses })
+    }
+
+    /// Every logged guess for a day/part, oldest first.
+    pub fn for_part(&self, day: u32, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |g| g.day == day && g.part == part)
+    }
+
+    /// Why `answer` should not be submitted for a day/part, if the log
+    /// already rules it out: the part is solved, the answer is known wrong,
+    /// or it falls outside the known too-low/too-high bounds.
+    pub fn reject_reason(&self, day: u32, part: u8, answer: &str) -> Option<String> {
+        if let Some(g) = self.for_part(day, part).find(|g| g.verdict == Verdict::Correct) {
+            return Some(format!("already solved with {}", g.answer));
+        }
+        if let Some(g) = self.for_part(day, part).find(|g| g.answer == answer && g.verdict.is_wrong()) {
+            return Some(format!("{} was already rejected as {}", g.answer, g.verdict.as_str()));
+        }
+
+        let (low, high) = self.bounds(day, part);
+        let value: i128 = answer.trim().parse().ok()?;
+        match (low, high) {
+            (Some(low), _) if value <= low => Some(format!("{} is not above the known too-low answer {}", value, low)),
+            (_, Some(high)) if value >= high => Some(format!("{} is not below the known too-high answer {}", value, high)),
+            _ => None,
+        }
+    }
+
+    /// The largest too-low and smallest too-high numeric answers so far.
+    pub fn bounds(&self, day: u32, part: u8) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict| {
+            self.for_part(day, part)
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.trim().parse::<i128>().ok())
+        };
+        (numeric(Verdict::TooLow).max(), numeric(Verdict::TooHigh).min())
+    }
+}
+
+/// Append a guess to the log at `path`, creating the file if needed.
+pub fn append(path: &Path, year: u32, day: u32, part: u8, answer: &str, verdict: Verdict) -> Result<(), String> {
+    let guess = Guess {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+    };
+
+    let entry = GuessFile { guess: vec![guess] };
+    let text = toml::to_string(&entry).map_err(|e| e.to_string())?;
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut f| write!(f, "\n{}", text))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(guesses: &[(u8, &str, Verdict)]) -> GuessLog {
+        let guesses = guesses
+            .iter()
+            .map(|&(part, answer, verdict)| Guess {
+                year: 2025,
+                day: 1,
+                part,
+                answer: answer.to_string(),
+                verdict,
+                timestamp: 0,
+            })
+            .collect();
+        GuessLog { guesses }
+    }
+
+    #[test]
+    fn duplicate_wrong_guesses_are_rejected() {
+        let log = log(&[(1, "42", Verdict::Wrong), (1, "50", Verdict::TooHigh)]);
+        assert_eq!(log.reject_reason(1, 1, "42").unwrap(), "42 was already rejected as wrong");
+        assert_eq!(log.reject_reason(1, 1, "50").unwrap(), "50 was already rejected as too high");
+        // Other parts and days have their own guesses.
+        assert_eq!(log.reject_reason(1, 2, "42"), None);
+        assert_eq!(log.reject_reason(2, 1, "42"), None);
+    }
+
+    #[test]
+    fn guesses_that_said_nothing_do_not_rule_an_answer_out() {
+        let log = log(&[
+            (1, "42", Verdict::RateLimited),
+            (1, "43", Verdict::WrongLevel),
+            (1, "44", Verdict::Unknown),
+        ]);
+        for answer in ["42", "43", "44"] {
+            assert_eq!(log.reject_reason(1, 1, answer), None);
+        }
+        assert_eq!(log.bounds(1, 1), (None, None));
+    }
+
+    #[test]
+    fn solved_parts_reject_everything() {
+        let log = log(&[(1, "7", Verdict::Correct)]);
+        assert_eq!(log.reject_reason(1, 1, "8").unwrap(), "already solved with 7");
+        assert_eq!(log.reject_reason(1, 2, "8"), None);
+    }
+
+    #[test]
+    fn bounds_are_the_tightest_too_low_and_too_high() {
+        let log = log(&[
+            (1, "10", Verdict::TooLow),
+            (1, "30", Verdict::TooLow),
+            (1, "90", Verdict::TooHigh),
+            (1, "70", Verdict::TooHigh),
+            (1, "50", Verdict::Wrong),
+            (1, "abc", Verdict::TooLow),
+            (2, "1000", Verdict::TooLow),
+        ]);
+        assert_eq!(log.bounds(1, 1), (Some(30), Some(70)));
+        assert_eq!(log.bounds(1, 2), (Some(1000), None));
+    }
+
+    #[test]
+    fn guesses_outside_the_bounds_are_rejected() {
+        let log = log(&[(1, "30", Verdict::TooLow), (1, "70", Verdict::TooHigh)]);
+        assert_eq!(log.reject_reason(1, 1, "20").unwrap(), "20 is not above the known too-low answer 30");
+        assert_eq!(log.reject_reason(1, 1, "30").unwrap(), "30 was already rejected as too low");
+        assert_eq!(log.reject_reason(1, 1, "80").unwrap(), "80 is not below the known too-high answer 70");
+        assert_eq!(log.reject_reason(1, 1, " 71 ").unwrap(), "71 is not below the known too-high answer 70");
+        assert_eq!(log.reject_reason(1, 1, "31"), None);
+        assert_eq!(log.reject_reason(1, 1, "69"), None);
+        // Non-numeric answers cannot be compared to the bounds.
+        assert_eq!(log.reject_reason(1, 1, "ABCDEF"), None);
+    }
+
+    #[test]
+    fn one_sided_bounds() {
+        let low = log(&[(1, "30", Verdict::TooLow)]);
+        assert!(low.reject_reason(1, 1, "-5").is_some());
+        assert_eq!(low.reject_reason(1, 1, "1000000000000000000000"), None);
+        let high = log(&[(1, "70", Verdict::TooHigh)]);
+        assert!(high.reject_reason(1, 1, "1000000000000000000000").is_some());
+        assert_eq!(high.reject_reason(1, 1, "-5"), None);
+    }
+
+    #[test]
+    fn verdicts_from_site_responses() {
+        let cases = [
+            ("<p>That's the right answer! You are one gold star closer.</p>", Verdict::Correct),
+            ("<p>That's not the right answer; your answer is too high.</p>", Verdict::TooHigh),
+            ("<p>That's not the right answer; your answer is too low.</p>", Verdict::TooLow),
+            ("<p>That's not the right answer. If you're stuck, ...</p>", Verdict::Wrong),
+            ("<p>You gave an answer too recently; you have 42s left to wait.</p>", Verdict::RateLimited),
+            ("<p>You don't seem to be solving the right level. Did you already complete it?</p>", Verdict::WrongLevel),
+            ("<html>Service Unavailable</html>", Verdict::Unknown),
+        ];
+        for (body, verdict) in cases {
+            assert_eq!(Verdict::from_response(body), verdict, "{}", body);
+        }
+    }
+}
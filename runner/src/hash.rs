@@ -0,0 +1,29 @@
+use solvers::Input;
+
+/// 64-bit FNV-1a hash, used to fingerprint inputs.
+///
+/// Unlike `std`'s `DefaultHasher`, the result is stable across Rust versions,
+/// so it is safe to store on disk.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes
+        .iter()
+        .fold(OFFSET, |hash, &b| (hash ^ b as u64).wrapping_mul(PRIME))
+}
+
+/// The FNV-1a hash of `text` as 16 hex digits.
+pub fn hex_digest(text: &str) -> String {
+    format!("{:016x}", fnv1a(text.as_bytes()))
+}
+
+/// Digest of an input's text, and of its parameter overrides if it has any,
+/// so that runs with different parameters are not mistaken for one another.
+pub fn input_digest(text: &str, input: &Input) -> String {
+    if input.params.is_empty() {
+        return hex_digest(text);
+    }
+    let params: Vec<String> = input.params.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    hex_digest(&format!("{}\n{}", text, params.join("\n")))
+}
@@ -0,0 +1,485 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::record::Record;
+
+/// Default location of the run history, relative to the repository root.
+pub const DEFAULT_PATH: &str = "history.toml";
+
+/// Slowdowns smaller than this are treated as noise by the regression check,
+/// whatever the percentage; microsecond-scale example runs jitter a lot.
+const NOISE_FLOOR: Duration = Duration::from_millis(1);
+
+/// One solved run of a day/part.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    /// The solution variant, or `None` for the day's main module.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    pub part: u8,
+    pub mode: String,
+    pub input_hash: String,
+    pub answer: String,
+    pub duration_ms: f64,
+    /// `HEAD` of the local repository at the time of the run, if known.
+    pub commit: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct HistoryFile {
+    #[serde(default)]
+    run: Vec<Entry>,
+}
+
+/// Every recorded run of one year, stored as `[[run]]` tables.
+#[derive(Debug, Default)]
+pub struct History {
+    runs: Vec<Entry>,
+}
+
+impl History {
+    /// Load the runs of `year` from `path`. A missing file yields an empty
+    /// history.
+    pub fn load(path: &Path, year: u32) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        };
+        let file: HistoryFile =
+            toml::from_str(&text).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        let runs = file.run.into_iter().filter(|e| e.year == year).collect();
+        Ok(History { runs })
+    }
+
+    /// Every recorded run of a day, oldest first.
+    pub fn for_day(&self, day: u32) -> impl Iterator<Item = &Entry> {
+        self.runs.iter().filter(move |e| e.day == day)
+    }
+
+    /// Earlier runs of the same day/part and variant on the same input.
+    fn same_input<'a>(
+        &'a self,
+        day: u32,
+        variant: Option<&'a str>,
+        part: u8,
+        input_hash: &'a str,
+    ) -> impl Iterator<Item = &'a Entry> {
+        self.for_day(day)
+            .filter(move |e| e.variant.as_deref() == variant && e.part == part && e.input_hash == input_hash)
+    }
+
+    /// Why `record` counts as a regression against this history, if it does:
+    /// its answer differs from the latest recorded answer for the same input,
+    /// or it took more than `max_slowdown_pct` percent longer than the median
+    /// of the recorded runs on that input.
+    pub fn regression(&self, record: &Record, max_slowdown_pct: f64) -> Option<String> {
+        let (Some(part), Some(hash), Some(answer), Some(elapsed)) =
+            (record.part, &record.input_hash, &record.answer, record.elapsed)
+        else {
+            return None;
+        };
+        if record.cached {
+            return None;
+        }
+        let variant = record.variant.as_ref().map_or(String::new(), |v| format!(" [{}]", v));
+        let name = format!("Day {}{} part {} ({})", record.day, variant, part, record.mode.unwrap_or("-"));
+
+        let previous: Vec<&Entry> = self.same_input(record.day, record.variant.as_deref(), part, hash).collect();
+        if let Some(last) = previous.last().filter(|e| &e.answer != answer) {
+            return Some(format!(
+                "{}: answer changed from {} to {} for the same input",
+                name, last.answer, answer
+            ));
+        }
+
+        let mut durations: Vec<f64> = previous.iter().map(|e| e.duration_ms).collect();
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_by(f64::total_cmp);
+        let baseline = Duration::from_secs_f64(durations[durations.len() / 2] / 1000.0);
+
+        let slowdown_pct = (elapsed.as_secs_f64() / baseline.as_secs_f64().max(f64::EPSILON) - 1.0) * 100.0;
+        (slowdown_pct > max_slowdown_pct && elapsed.saturating_sub(baseline) > NOISE_FLOOR).then(|| {
+            format!(
+                "{}: took {:.2?}, {:.0}% slower than its {:.2?} baseline",
+                name, elapsed, slowdown_pct, baseline
+            )
+        })
+    }
+}
+
+/// Append every solved record to the history at `path`, creating the file
+/// if needed.
+pub fn append(path: &Path, year: u32, records: &[Record], commit: Option<String>) -> Result<(), String> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+    let run: Vec<Entry> = records
+        .iter()
+        // Cached answers were logged when they were first computed.
+        .filter(|r| r.failure.is_none() && !r.cached)
+        .filter_map(|r| {
+            Some(Entry {
+                year,
+                day: r.day,
+                variant: r.variant.clone(),
+                part: r.part?,
+                mode: r.mode?.to_string(),
+                input_hash: r.input_hash.clone()?,
+                answer: r.answer.clone()?,
+                duration_ms: r.elapsed?.as_secs_f64() * 1000.0,
+                commit: commit.clone(),
+                timestamp,
+            })
+        })
+        .collect();
+    if run.is_empty() {
+        return Ok(());
+    }
+
+    let text = toml::to_string(&HistoryFile { run }).map_err(|e| e.to_string())?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut f| write!(f, "\n{}", text))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Print every recorded run of `day`, grouped by variant, part and input,
+/// with the change in duration from one run to the next.
+pub fn run(path: &Path, year: u32, day: u32) -> Result<(), String> {
+    let history = History::load(path, year)?;
+    if history.for_day(day).next().is_none() {
+        println!("No recorded runs of day {} in {}", day, path.display());
+        return Ok(());
+    }
+
+    let mut variants: Vec<Option<&str>> = history.for_day(day).map(|e| e.variant.as_deref()).collect();
+    variants.sort();
+    variants.dedup();
+
+    for variant in variants {
+        for part in 1..=2u8 {
+            for mode in ["example", "real"] {
+                let runs: Vec<&Entry> = history
+                    .for_day(day)
+                    .filter(|e| e.variant.as_deref() == variant && e.part == part && e.mode == mode)
+                    .collect();
+                if runs.is_empty() {
+                    continue;
+                }
+
+                let name = variant.map_or(String::new(), |v| format!(" [{}]", v));
+                println!("Day {}{} part {} ({})", day, name, part, mode);
+                println!(
+                    "  {:<10}  {:<7}  {:<8}  {:>10}  {:>8}  Answer",
+                    "Date", "Commit", "Input", "Time", "Change"
+                );
+                let mut previous: Option<&Entry> = None;
+                for e in &runs {
+                    // Comparing durations across different inputs is meaningless.
+                    let change = previous
+                        .filter(|p| p.input_hash == e.input_hash)
+                        .map_or("-".to_string(), |p| {
+                            format!("{:+.0}%", (e.duration_ms / p.duration_ms.max(f64::EPSILON) - 1.0) * 100.0)
+                        });
+                    let answer_changed = previous.is_some_and(|p| p.input_hash == e.input_hash && p.answer != e.answer);
+                    println!(
+                        "  {:<10}  {:<7}  {:<8}  {:>10}  {:>8}  {}{}",
+                        date(e.timestamp),
+                        e.commit.as_deref().map_or("-", |c| &c[..c.len().min(7)]),
+                        &e.input_hash[..e.input_hash.len().min(8)],
+                        format!("{:.2?}", Duration::from_secs_f64(e.duration_ms / 1000.0)),
+                        change,
+                        e.answer,
+                        if answer_changed { "  (changed)" } else { "" }
+                    );
+                    previous = Some(e);
+                }
+
+                let best = runs.iter().map(|e| e.duration_ms).fold(f64::INFINITY, f64::min);
+                let (first, latest) = (runs[0].duration_ms, runs[runs.len() - 1].duration_ms);
+                println!(
+                    "  {} run(s); best {:.2?}, first {:.2?}, latest {:.2?}",
+                    runs.len(),
+                    Duration::from_secs_f64(best / 1000.0),
+                    Duration::from_secs_f64(first / 1000.0),
+                    Duration::from_secs_f64(latest / 1000.0)
+                );
+                println!();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The commit `HEAD` points at, read straight from `.git` under `root` so
+/// that neither `git` nor a git library is needed.
+pub fn git_commit(root: &Path) -> Option<String> {
+    let mut git_dir = root.join(".git");
+    // In a worktree or submodule `.git` is a file pointing at the real directory.
+    if git_dir.is_file() {
+        let text = fs::read_to_string(&git_dir).ok()?;
+        git_dir = root.join(text.strip_prefix("gitdir:")?.trim());
+    }
+    // A worktree's directory only holds its own `HEAD` and a few per-worktree
+    // refs; branches and `packed-refs` live in the main repository's, which
+    // `commondir` points at.
+    let common_dir = match fs::read_to_string(git_dir.join("commondir")) {
+        Ok(text) => git_dir.join(text.trim()),
+        Err(_) => git_dir.clone(),
+    };
+
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let Some(reference) = head.trim().strip_prefix("ref:") else {
+        // Detached HEAD holds the hash itself.
+        return Some(head.trim().to_string());
+    };
+    let reference = reference.trim();
+
+    for dir in [&git_dir, &common_dir] {
+        if let Ok(hash) = fs::read_to_string(dir.join(reference)) {
+            return Some(hash.trim().to_string());
+        }
+    }
+    // The ref may only exist in `packed-refs`, as `<hash> <ref>` lines.
+    fs::read_to_string(common_dir.join("packed-refs"))
+        .ok()?
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .find(|&(_, name)| name == reference)
+        .map(|(hash, _)| hash.to_string())
+}
+
+/// `YYYY-MM-DD` (UTC) for a Unix timestamp.
+fn date(timestamp: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm.
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Where runs are logged and whether they are checked for regressions.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    pub path: PathBuf,
+    /// Repository root, for looking up the current commit.
+    pub root: PathBuf,
+    /// Puzzle year the recorded runs belong to.
+    pub year: u32,
+    /// Fail runs that got more than this many percent slower, or changed
+    /// their answer; `None` only logs.
+    pub max_slowdown_pct: Option<f64>,
+    /// Append the runs to the history; off for throwaway runs like those of
+    /// `watch`.
+    pub log: bool,
+}
+
+impl Recorder {
+    /// Check `records` against the history if requested, then log them.
+    /// Returns `false` if the check found a regression. Problems with the
+    /// history file itself are reported but never fail the run.
+    pub fn record(&self, records: &[Record]) -> bool {
+        let mut ok = true;
+        if let Some(max_slowdown_pct) = self.max_slowdown_pct {
+            match History::load(&self.path, self.year) {
+                Ok(history) => {
+                    for reason in records.iter().filter_map(|r| history.regression(r, max_slowdown_pct)) {
+                        eprintln!("📉 Regression: {}", reason);
+                        ok = false;
+                    }
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+        }
+        if !self.log {
+            return ok;
+        }
+        if let Err(e) = append(&self.path, self.year, records, git_commit(&self.root)) {
+            eprintln!("{}", e);
+        }
+        ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    /// A solved real-input run of day 1 part 1.
+    fn record(answer: &str, elapsed_ms: f64) -> Record {
+        Record {
+            part: Some(1),
+            mode: Some("real"),
+            answer: Some(answer.to_string()),
+            elapsed: Some(Duration::from_secs_f64(elapsed_ms / 1000.0)),
+            input_hash: Some("abc".to_string()),
+            ..Record::skipped(1)
+        }
+    }
+
+    fn history(runs: &[(&str, f64)]) -> History {
+        let runs = runs
+            .iter()
+            .map(|&(answer, duration_ms)| Entry {
+                year: 2025,
+                day: 1,
+                variant: None,
+                part: 1,
+                mode: "real".to_string(),
+                input_hash: "abc".to_string(),
+                answer: answer.to_string(),
+                duration_ms,
+                commit: None,
+                timestamp: 0,
+            })
+            .collect();
+        History { runs }
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(86_399), "1970-01-01");
+        assert_eq!(date(86_400), "1970-01-02");
+        // Leap days, including the century rules.
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(951_868_800), "2000-03-01");
+        assert_eq!(date(4_107_542_400), "2100-03-01");
+        assert_eq!(date(1_709_164_800), "2024-02-29");
+        assert_eq!(date(1_764_547_200), "2025-12-01");
+        assert_eq!(date(1_767_225_599), "2025-12-31");
+    }
+
+    #[test]
+    fn commits_of_worktrees_come_from_the_common_dir() {
+        let repo = test_support::scratch_dir("git");
+        let git = repo.join("main/.git");
+        fs::create_dir_all(git.join("refs/heads")).unwrap();
+        fs::create_dir_all(git.join("worktrees/feature")).unwrap();
+        fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(git.join("refs/heads/main"), "1111\n").unwrap();
+        fs::write(git.join("packed-refs"), "# pack-refs with: peeled\n2222 refs/heads/feature\n").unwrap();
+        assert_eq!(git_commit(&repo.join("main")).as_deref(), Some("1111"));
+
+        let worktree = repo.join("feature");
+        fs::create_dir_all(&worktree).unwrap();
+        fs::write(worktree.join(".git"), format!("gitdir: {}\n", git.join("worktrees/feature").display())).unwrap();
+        fs::write(git.join("worktrees/feature/commondir"), "../..\n").unwrap();
+        fs::write(git.join("worktrees/feature/HEAD"), "ref: refs/heads/feature\n").unwrap();
+        assert_eq!(git_commit(&worktree).as_deref(), Some("2222"));
+
+        fs::write(git.join("refs/heads/feature"), "3333\n").unwrap();
+        assert_eq!(git_commit(&worktree).as_deref(), Some("3333"));
+
+        fs::write(git.join("worktrees/feature/HEAD"), "4444\n").unwrap();
+        assert_eq!(git_commit(&worktree).as_deref(), Some("4444"));
+        assert_eq!(git_commit(&repo), None);
+    }
+
+    #[test]
+    fn first_runs_are_never_regressions() {
+        assert_eq!(history(&[]).regression(&record("42", 500.0), 10.0), None);
+    }
+
+    #[test]
+    fn slowdowns_are_measured_against_the_median() {
+        // Median 100ms; the 1000ms outlier does not drag the baseline up.
+        let history = history(&[("42", 90.0), ("42", 1000.0), ("42", 100.0)]);
+        assert_eq!(history.regression(&record("42", 109.0), 10.0), None);
+        assert_eq!(history.regression(&record("42", 110.0), 10.0), None);
+        let reason = history.regression(&record("42", 111.0), 10.0).unwrap();
+        assert!(reason.starts_with("Day 1 part 1 (real): took 111.00ms, 11% slower"), "{}", reason);
+        assert_eq!(history.regression(&record("42", 50.0), 10.0), None);
+    }
+
+    #[test]
+    fn slowdowns_within_the_noise_floor_are_ignored() {
+        let history = history(&[("42", 0.01)]);
+        assert_eq!(history.regression(&record("42", 0.9), 10.0), None);
+        assert!(history.regression(&record("42", 1.2), 10.0).is_some());
+    }
+
+    #[test]
+    fn changed_answers_are_regressions_whatever_the_time() {
+        let history = history(&[("41", 100.0), ("42", 100.0)]);
+        let reason = history.regression(&record("41", 1.0), 1000.0).unwrap();
+        assert_eq!(reason, "Day 1 part 1 (real): answer changed from 42 to 41 for the same input");
+        assert_eq!(history.regression(&record("42", 1.0), 1000.0), None);
+    }
+
+    #[test]
+    fn only_the_same_input_part_and_variant_count() {
+        let history = history(&[("42", 1.0)]);
+        let other_input = Record {
+            input_hash: Some("def".to_string()),
+            ..record("7", 100.0)
+        };
+        assert_eq!(history.regression(&other_input, 10.0), None);
+        let other_part = Record {
+            part: Some(2),
+            ..record("7", 100.0)
+        };
+        assert_eq!(history.regression(&other_part, 10.0), None);
+        let variant = Record {
+            variant: Some("fast".to_string()),
+            ..record("7", 100.0)
+        };
+        assert_eq!(history.regression(&variant, 10.0), None);
+    }
+
+    #[test]
+    fn cached_and_failed_runs_are_not_checked() {
+        let history = history(&[("42", 1.0)]);
+        let cached = Record {
+            cached: true,
+            ..record("7", 100.0)
+        };
+        assert_eq!(history.regression(&cached, 10.0), None);
+        let failed = Record {
+            answer: None,
+            ..record("7", 100.0)
+        };
+        assert_eq!(history.regression(&failed, 10.0), None);
+    }
+
+    #[test]
+    fn appended_runs_load_back_by_year() {
+        let path = test_support::scratch_dir("history").join("history.toml");
+        let cached = Record {
+            cached: true,
+            ..record("7", 1.0)
+        };
+        append(&path, 2025, &[record("42", 12.5), cached, Record::skipped(2)], Some("c0ffee".to_string())).unwrap();
+        append(&path, 2024, &[record("43", 1.0)], None).unwrap();
+
+        let history = History::load(&path, 2025).unwrap();
+        let runs: Vec<&Entry> = history.for_day(1).collect();
+        assert_eq!(runs.len(), 1);
+        assert_eq!((runs[0].answer.as_str(), runs[0].duration_ms), ("42", 12.5));
+        assert_eq!(runs[0].commit.as_deref(), Some("c0ffee"));
+        assert!(runs[0].timestamp > 0);
+        assert_eq!(History::load(&path, 2024).unwrap().for_day(1).count(), 1);
+        assert_eq!(History::load(&path.with_file_name("missing.toml"), 2025).unwrap().runs.len(), 0);
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt, fs, panic,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc, Once},
+    thread,
+    time::Duration,
+};
+
+use solvers::Input;
+
+use crate::{
+    alloc::{self, MemStats},
+    cache::Cache,
+    cpu, hash, mode_name,
+};
+
+/// Default directory for repro bundles.
+pub const DEFAULT_REPRO_DIR: &str = "repro";
+
+/// How solvers are isolated from the runner.
+#[derive(Clone, Debug)]
+pub struct Isolation {
+    /// Give up on a solver after this long; `None` waits forever.
+    pub timeout: Option<Duration>,
+    /// Per-day overrides of `timeout`.
+    pub day_timeouts: BTreeMap<u32, Option<Duration>>,
+    /// Where repro bundles for panics and timeouts are written.
+    pub repro_dir: PathBuf,
+    /// Count the solver's allocations.
+    pub track_memory: bool,
+    /// Answers of earlier runs, returned by [`Record::run`](crate::record::Record::run)
+    /// instead of running the solver again; `None` when caching is off.
+    pub cache: Option<Arc<Cache>>,
+}
+
+impl Isolation {
+    /// The timeout that applies to `day`.
+    pub fn timeout_for(&self, day: u32) -> Option<Duration> {
+        self.day_timeouts.get(&day).copied().unwrap_or(self.timeout)
+    }
+}
+
+/// Why an isolated solver did not produce an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The solver panicked with this message.
+    Panic(String),
+    /// The solver did not finish within the timeout.
+    Timeout(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout(limit) => write!(f, "timed out after {:.2?}", limit),
+        }
+    }
+}
+
+/// A solver's answer and the CPU time its worker thread spent on it.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub answer: String,
+    pub cpu: Option<Duration>,
+    /// Allocation counters, when memory tracking is on.
+    pub mem: Option<MemStats>,
+}
+
+thread_local! {
+    /// Set on solver worker threads, whose panics are captured rather than printed.
+    static IS_WORKER: Cell<bool> = const { Cell::new(false) };
+    /// The formatted message of the last panic on this worker thread.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that captures panics on worker threads and defers
+/// to the default hook everywhere else.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IS_WORKER.with(Cell::get) {
+                let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+                let message = match info.location() {
+                    Some(loc) => format!("{} ({}:{}:{})", message, loc.file(), loc.line(), loc.column()),
+                    None => message.to_string(),
+                };
+                LAST_PANIC.with(|p| *p.borrow_mut() = Some(message));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Run a day/part on a worker thread so that a panic or an endless loop
+/// cannot take down the runner.
+///
+/// Returns `Ok(None)` if the day is not implemented. A timed-out worker is
+/// left running in the background, since threads cannot be killed; it goes
+/// away when the runner exits.
+pub fn run(part: u8, input: &Input, isolation: &Isolation) -> Result<Option<Solved>, Failure> {
+    install_panic_hook();
+
+    let (tx, rx) = mpsc::channel();
+    let input = input.clone();
+    let timeout = isolation.timeout_for(input.day);
+    let track_memory = isolation.track_memory;
+    thread::spawn(move || {
+        IS_WORKER.with(|w| w.set(true));
+        let cpu_start = cpu::thread_cpu_time();
+        if track_memory {
+            alloc::start();
+        }
+        let result = panic::catch_unwind(|| solvers::run_input(part, &input));
+        let mem = track_memory.then(alloc::finish);
+        let cpu = cpu::thread_cpu_time()
+            .zip(cpu_start)
+            .map(|(end, start)| end.saturating_sub(start));
+        let result = result.map(|answer| answer.map(|answer| Solved { answer, cpu, mem })).map_err(|payload| {
+            LAST_PANIC
+                .with(|p| p.borrow_mut().take())
+                .unwrap_or_else(|| payload_message(payload.as_ref()))
+        });
+        // The receiver is gone if the runner already gave up on us.
+        let _ = tx.send(result);
+    });
+
+    let received = match timeout {
+        Some(limit) => rx.recv_timeout(limit).map_err(|_| Failure::Timeout(limit))?,
+        None => rx.recv().expect("worker always sends a result"),
+    };
+    received.map_err(Failure::Panic)
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Write a repro bundle for a failed run into `dir`: a `repro.toml` with the
+/// day, variant, part, input location, input hash, parameters and failure,
+/// plus a copy of the input text. Returns the bundle directory.
+pub fn write_repro(dir: &Path, part: u8, input: &Input, failure: &Failure) -> Result<PathBuf, String> {
+    let text = input.read().ok();
+    // The same digest as the run's record, so the two can be matched up.
+    let input_hash = text
+        .as_deref()
+        .map_or("unreadable".to_string(), |text| hash::input_digest(text, input));
+    let (kind, message) = match failure {
+        Failure::Panic(message) => ("panic", message.clone()),
+        Failure::Timeout(_) => ("timeout", failure.to_string()),
+    };
+
+    let variant = input.variant.as_ref().map_or(String::new(), |v| format!("_{}", v));
+    let bundle = dir.join(format!(
+        "{}-day{:02}{}-part{}-{}-{}-{}",
+        input.year,
+        input.day,
+        variant,
+        part,
+        mode_name(input.example),
+        kind,
+        input_hash
+    ));
+    fs::create_dir_all(&bundle).map_err(|e| format!("Failed to create {}: {}", bundle.display(), e))?;
+
+    let mut report = toml::Table::new();
+    report.insert("year".into(), (input.year as i64).into());
+    report.insert("day".into(), (input.day as i64).into());
+    if let Some(variant) = &input.variant {
+        report.insert("variant".into(), variant.as_str().into());
+    }
+    report.insert("part".into(), (part as i64).into());
+    report.insert("mode".into(), mode_name(input.example).into());
+    report.insert("input".into(), input.to_string().into());
+    report.insert("input_hash".into(), input_hash.into());
+    if !input.params.is_empty() {
+        let params: toml::Table = input.params.iter().map(|(k, &v)| (k.clone(), v.into())).collect();
+        report.insert("params".into(), params.into());
+    }
+    report.insert("failure".into(), kind.into());
+    report.insert("message".into(), message.into());
+
+    let report_path = bundle.join("repro.toml");
+    fs::write(&report_path, report.to_string())
+        .map_err(|e| format!("Failed to write {}: {}", report_path.display(), e))?;
+
+    if let Some(text) = text {
+        let input_path = bundle.join("input.txt");
+        fs::write(&input_path, text).map_err(|e| format!("Failed to write {}: {}", input_path.display(), e))?;
+    }
+
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use solvers::Source;
+
+    use super::*;
+    use crate::{record::Record, test_support::scratch_dir};
+
+    fn isolation(dir: &Path, timeout: Duration) -> Isolation {
+        Isolation {
+            timeout: Some(timeout),
+            day_timeouts: Default::default(),
+            repro_dir: dir.join("repro"),
+            track_memory: false,
+            cache: None,
+        }
+    }
+
+    #[test]
+    fn panics_are_captured_with_a_repro_bundle() {
+        let dir = scratch_dir("isolate-panic");
+        let isolation = isolation(&dir, Duration::from_secs(30));
+        let input = Input::conventional(2025, 1, false).with_source(Source::Text("L5\nX5\n".to_string()));
+
+        let failure = run(1, &input, &isolation).unwrap_err();
+        let Failure::Panic(message) = &failure else {
+            panic!("expected a panic, got {:?}", failure);
+        };
+        assert!(message.starts_with("Invalid direction in rotation 'X5' ("), "{}", message);
+
+        let record = Record::run(1, &input, None, &isolation);
+        assert_eq!(record.failure.as_ref(), Some(&failure));
+        let bundles: Vec<PathBuf> = fs::read_dir(&isolation.repro_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(bundles.len(), 1);
+        let report: toml::Table = fs::read_to_string(bundles[0].join("repro.toml")).unwrap().parse().unwrap();
+        assert_eq!(report["failure"].as_str(), Some("panic"));
+        assert_eq!(report["message"].as_str(), Some(message.as_str()));
+        assert_eq!(fs::read_to_string(bundles[0].join("input.txt")).unwrap(), "L5\nX5\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn solvers_past_the_timeout_are_abandoned() {
+        use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+        // Opening a FIFO without a writer blocks, so the solver never finishes.
+        let dir = scratch_dir("isolate-timeout");
+        let fifo = dir.join("input.fifo");
+        let path = CString::new(fifo.as_os_str().as_bytes()).unwrap();
+        // SAFETY: `path` is a valid NUL-terminated string for the duration of the call.
+        assert_eq!(unsafe { libc::mkfifo(path.as_ptr(), 0o600) }, 0);
+        let input = Input::conventional(2025, 1, false).with_source(Source::Path(fifo));
+
+        let limit = Duration::from_millis(50);
+        let started = Instant::now();
+        assert_eq!(run(1, &input, &isolation(&dir, limit)).unwrap_err(), Failure::Timeout(limit));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,9 +1,229 @@
-use clap::Parser;
+use std::{
+    ffi::OsString,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
+
+use answers::Answers;
+use cache::Cache;
+use config::Config;
+use history::Recorder;
+use isolate::Isolation;
+use output::Format;
+use record::{Record, Status};
+use solvers::{Input, Source};
+use variants::VariantSelector;
+
+mod all;
+mod alloc;
+mod answers;
+mod bench;
+mod cache;
+mod calendar;
+mod client;
+mod config;
+mod cpu;
+mod diff;
+mod fetch;
+mod gate;
+mod guesses;
+mod hash;
+mod history;
+mod isolate;
+mod output;
+mod params;
+mod pool;
+mod readme;
+mod record;
+mod repl;
+mod report;
+mod scaffold;
+mod serve;
+mod submit;
+/// Helpers shared by the unit tests: scratch directories, conventional
+/// inputs, and a local stand-in for the Advent of Code site.
+#[cfg(test)]
+mod test_support;
+mod tui;
+mod variants;
+mod verify;
+mod watch;
+
+/// Last day on the Advent calendar.
+const LAST_DAY: u32 = 25;
+
+/// Solver timeout in seconds when neither `--timeout` nor aoc.toml set one.
+const DEFAULT_TIMEOUT: f64 = 60.0;
 
 /// Advent of Code 2025 runner
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, arg_required_else_help = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Path to the answer registry [default: <root>/answers.toml]
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+
+    /// Output format for run results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Print only the bare answer(s), one per line
+    #[arg(long, short, global = true)]
+    quiet: bool,
+
+    /// Give up on a solver after this many seconds (0 waits forever);
+    /// overrides `timeout` in aoc.toml [default: 60]
+    #[arg(long, global = true, value_name = "SECONDS")]
+    timeout: Option<f64>,
+
+    /// Directory for repro bundles of panicking or timed-out solvers [default: <root>/repro]
+    #[arg(long, global = true)]
+    repro_dir: Option<PathBuf>,
+
+    /// File that every single and `all` run is logged to [default: <root>/history.toml]
+    #[arg(long, global = true)]
+    history: Option<PathBuf>,
+
+    /// Fail if a run got more than PERCENT slower than its recorded baseline,
+    /// or its answer changed for the same input
+    #[arg(
+        long,
+        global = true,
+        value_name = "PERCENT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "25"
+    )]
+    check_regression: Option<f64>,
+
+    /// Count allocations, bytes allocated and peak live bytes of each solver
+    #[arg(long, global = true)]
+    mem: bool,
+
+    /// Puzzle year; overrides `year` in aoc.toml [default: 2025]
+    #[arg(long, global = true)]
+    year: Option<u32>,
+
+    /// Run the solvers even if `cache` is on in aoc.toml
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Do not log runs to the history file
+    #[arg(long, global = true)]
+    no_history: bool,
+}
+
+/// The first argument after the program name that is neither an option nor
+/// the value of one.
+fn first_positional(args: &[OsString]) -> Option<&str> {
+    let command = Cli::command();
+    let takes_value = |long: Option<&str>, short: Option<char>| {
+        command.get_arguments().any(|arg| {
+            arg.get_action().takes_values()
+                && ((long.is_some() && arg.get_long() == long) || (short.is_some() && arg.get_short() == short))
+        })
+    };
+    let mut rest = args.iter().skip(1).filter_map(|a| a.to_str());
+    while let Some(arg) = rest.next() {
+        let value_follows = if arg == "--" {
+            return rest.next();
+        } else if let Some(long) = arg.strip_prefix("--") {
+            !long.contains('=') && takes_value(Some(long), None)
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // In a cluster like `-vj`, only the last flag can take the next argument.
+            takes_value(None, shorts.chars().last())
+        } else {
+            return Some(arg);
+        };
+        if value_follows {
+            rest.next();
+        }
+    }
+    None
+}
+
+impl Cli {
+    /// Parse `args`, treating a command line that starts with a day instead
+    /// of a subcommand as a single run: `runner 1 2 real` is
+    /// `runner run 1 2 real`.
+    fn try_parse_args<I, T>(args: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+        let error = match Cli::try_parse_from(&args) {
+            Err(e) if e.kind() == ErrorKind::InvalidSubcommand => e,
+            parsed => return parsed,
+        };
+        let mut with_run = args.clone();
+        with_run.insert(1.min(args.len()), "run".into());
+        match Cli::try_parse_from(with_run) {
+            Ok(cli) => Ok(cli),
+            // A number was meant as a day; anything else is a mistyped subcommand.
+            Err(e) if first_positional(&args).is_some_and(|a| a.parse::<u32>().is_ok()) => Err(e),
+            Err(_) => Err(error),
+        }
+    }
+
+    /// `--timeout` wins over every timeout in the configuration. The result
+    /// cache is skipped with `--no-cache` and when measuring memory, which a
+    /// cached answer has no figures for; a cache that cannot be loaded is
+    /// reported and skipped too.
+    fn isolation(&self, config: &Config) -> Isolation {
+        let timeout = self.timeout.or(config.timeout()).unwrap_or(DEFAULT_TIMEOUT);
+        let day_timeouts = match self.timeout {
+            Some(_) => Default::default(),
+            None => config
+                .day_timeouts()
+                .map(|(day, seconds)| (day, config::timeout_duration(seconds)))
+                .collect(),
+        };
+        let cache = if config.cache && !self.no_cache && !self.mem {
+            Cache::load(&config.resolve(cache::DEFAULT_DIR))
+                .map_err(|e| eprintln!("{}", e))
+                .ok()
+                .map(Arc::new)
+        } else {
+            None
+        };
+        Isolation {
+            timeout: config::timeout_duration(timeout),
+            day_timeouts,
+            repro_dir: resolve(&self.repro_dir, config, isolate::DEFAULT_REPRO_DIR),
+            track_memory: self.mem,
+            cache,
+        }
+    }
+
+    fn recorder(&self, config: &Config) -> Recorder {
+        Recorder {
+            path: resolve(&self.history, config, history::DEFAULT_PATH),
+            root: config.root.clone(),
+            year: config.year,
+            max_slowdown_pct: self.check_regression,
+            log: !self.no_history,
+        }
+    }
+}
+
+/// A path given on the command line, relative to the current directory, or
+/// else `default` relative to the repository root.
+fn resolve(given: &Option<PathBuf>, config: &Config, default: &str) -> PathBuf {
+    given.clone().unwrap_or_else(|| config.resolve(default))
+}
+
+/// Arguments for running a single day/part.
+#[derive(Args, Debug)]
+struct RunArgs {
     /// Day number to run (e.g. 1 or 2)
     day: u32,
 
@@ -11,34 +231,744 @@ struct Cli {
     #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
     part: u8,
 
-    /// Which input to use: example or real
+    /// Which input to use: example or real; defaults to `mode` in aoc.toml
     #[arg(value_parser = ["example", "real"], value_name = "example|real")]
-    mode: String,
+    mode: Option<String>,
 
-    /// Optional expected answer; if provided, result will be validated against it
+    /// Optional expected answer; overrides the answer registry when provided
     expected: Option<String>,
+
+    /// Read the input from this file instead of `inputs/`, or `-` for stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "input_text")]
+    input: Option<PathBuf>,
+
+    /// Use this text as the input instead of `inputs/`
+    #[arg(long, value_name = "TEXT")]
+    input_text: Option<String>,
+
+    /// Run a real input even if the example answer is wrong when `gate` is
+    /// on in aoc.toml
+    #[arg(long)]
+    no_gate: bool,
+
+    /// Solve with the `dayNN_<NAME>.rs` variant instead of `dayNN.rs`
+    #[arg(long, value_name = "NAME")]
+    variant: Option<String>,
+
+    /// Set one of the day's parameters (see `params <day>`); repeatable
+    #[arg(long = "param", short = 'p', value_name = "NAME=VALUE", value_parser = solvers::params::parse_assignment)]
+    params: Vec<(String, i64)>,
+}
+
+impl RunArgs {
+    /// The input selected by `--input`/`--input-text`, or the conventional
+    /// one, for the selected variant.
+    fn input(&self, year: u32, day: u32, use_example: bool) -> Input {
+        let input = Input::conventional(year, day, use_example).with_variant(self.variant.clone());
+        if let Some(text) = &self.input_text {
+            return input.with_source(Source::Text(text.clone()));
+        }
+        match &self.input {
+            // Read stdin once up front so the solver can re-read the text freely.
+            Some(path) if path.as_os_str() == "-" => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).unwrap_or_else(|e| {
+                    eprintln!("Failed to read <stdin>: {}", e);
+                    std::process::exit(2);
+                });
+                input.with_source(Source::Text(text))
+            }
+            Some(path) => input.with_source(Source::Path(path.clone())),
+            None => input,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run one day and part; the default, so `runner 1 2 real` is `runner run 1 2 real`
+    Run(RunArgs),
+
+    /// Run every implemented day and part and print a summary table
+    All {
+        /// Which inputs to run: example, real or both
+        #[arg(
+            long,
+            default_value = "both",
+            value_parser = ["example", "real", "both"],
+            value_name = "example|real|both"
+        )]
+        mode: String,
+
+        /// Number of worker threads; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+
+        /// Also run every `dayNN_<name>.rs` variant and compare them
+        #[arg(long)]
+        variants: bool,
+    },
+
+    /// Check every answer in the registry and exit non-zero on any mismatch
+    Verify {
+        /// Number of worker threads; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+
+    /// Time solutions over repeated runs and report statistics
+    Bench {
+        /// Day number to benchmark, or `all`
+        #[arg(value_name = "DAY|all")]
+        day: DaySelector,
+
+        /// Part number to benchmark; both parts if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Which input to use: example or real
+        #[arg(long, default_value = "real", value_parser = ["example", "real"], value_name = "example|real")]
+        mode: String,
+
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+
+        /// Timed runs
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Benchmark the `dayNN_<NAME>.rs` variant instead of `dayNN.rs`
+        #[arg(long, value_name = "NAME", conflicts_with = "variants")]
+        variant: Option<String>,
+
+        /// Benchmark every variant and rank them
+        #[arg(long)]
+        variants: bool,
+    },
+
+    /// Create a new day's solver module and empty input files
+    New {
+        /// Day number to scaffold
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Read the example input from stdin; an answer after a `---` line is
+        /// registered as the expected part 1 example answer
+        #[arg(long)]
+        stdin: bool,
+    },
+
+    /// Rebuild and re-run a day/part whenever its source or input changes
+    Watch {
+        /// Day number to run
+        day: u32,
+
+        /// Part number to run (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Which input to use: example or real
+        #[arg(value_parser = ["example", "real"], value_name = "example|real")]
+        mode: String,
+
+        /// Polling interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+
+    /// Download missing puzzle inputs into `inputs/`
+    Fetch {
+        /// Day number to fetch, or `all`
+        #[arg(value_name = "DAY|all")]
+        day: DaySelector,
+
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+
+    /// Solve a part on the real input and submit the answer
+    Submit {
+        /// Day number to submit
+        day: u32,
+
+        /// Part number to submit (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Local log of every submitted answer and its verdict [default: <root>/guesses.toml]
+        #[arg(long)]
+        log: Option<PathBuf>,
+
+        /// Submit without asking for confirmation
+        #[arg(long, short)]
+        yes: bool,
+
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+
+    /// Interactive terminal dashboard for the whole calendar
+    Tui,
+
+    /// Interactive shell for running solvers and inspecting inputs
+    Repl,
+
+    /// Serve the solvers over a local JSON HTTP API
+    Serve {
+        /// Port to listen on (localhost only)
+        #[arg(long, default_value_t = 8025)]
+        port: u16,
+
+        /// Number of request handler threads; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+
+    /// List a day's tunable parameters and their values
+    Params {
+        /// Day number to list
+        day: u32,
+    },
+
+    /// List a day's solution variants and the parts each implements
+    Variants {
+        /// Day number to list
+        day: u32,
+    },
+
+    /// Find where two variants disagree and shrink the input to a minimal case
+    Diff {
+        /// Day number to test
+        day: u32,
+
+        /// Part number to test (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// First variant; `default` is the day's main module
+        #[arg(long, value_name = "VARIANT")]
+        a: String,
+
+        /// Second variant
+        #[arg(long, value_name = "VARIANT")]
+        b: String,
+
+        /// Input to start from [default: the day's input for --mode]
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Which kind of input it is: example or real
+        #[arg(long, default_value = "real", value_parser = ["example", "real"], value_name = "example|real")]
+        mode: String,
+
+        /// Where to write the minimized input [default: inputs/dayNN_example_candidate.txt]
+        #[arg(long, value_name = "PATH")]
+        out: Option<PathBuf>,
+    },
+
+    /// Manage the result cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
+    /// Show the recorded runs of a day and how their timings changed
+    History {
+        /// Day number to show
+        day: u32,
+    },
+
+    /// Print a star calendar of verified parts with runtime badges
+    Calendar {
+        /// Number of worker threads; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+
+    /// Print the README results table, or rewrite it in place with --update
+    Readme {
+        /// Rewrite the marked results section of README.md
+        #[arg(long)]
+        update: bool,
+
+        /// Timed runs per part for the median runtime
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Number of worker threads for checking answers; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+
+    /// Write a self-contained HTML report of every day and part
+    Report {
+        /// Where to write the report [default: <root>/report.html]
+        #[arg(long, value_name = "PATH")]
+        out: Option<PathBuf>,
+
+        /// Show real answers as dots, e.g. for sharing the report
+        #[arg(long)]
+        mask_answers: bool,
+
+        /// Timed runs per part for the median runtime
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Number of worker threads for running the parts; defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<usize>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheAction {
+    /// Delete every stored answer
+    Clear,
+}
+
+/// Options for talking to the Advent of Code site.
+#[derive(Args, Debug)]
+struct ClientArgs {
+    /// Base URL of the Advent of Code site
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Session cookie value; falls back to the `.aoc-session` file
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Minimum number of seconds between requests
+    #[arg(long, default_value_t = 3.0, value_name = "SECONDS")]
+    delay: f64,
+}
+
+impl ClientArgs {
+    fn client(self, config: &Config) -> Result<client::Client, String> {
+        let session = client::session_token(self.session, &config.resolve(client::SESSION_FILE))?;
+        Ok(client::Client::new(
+            &self.base_url,
+            session,
+            config.year,
+            Duration::from_secs_f64(self.delay),
+        ))
+    }
+}
+
+/// A single day, or every implemented day.
+#[derive(Clone, Copy, Debug)]
+enum DaySelector {
+    All,
+    Day(u32),
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelector::All);
+        }
+        s.parse()
+            .map(DaySelector::Day)
+            .map_err(|_| format!("expected a day number or `all`, got '{}'", s))
+    }
 }
 
 fn main() {
-    let cli = Cli::parse();
+    let cli = Cli::try_parse_args(std::env::args_os()).unwrap_or_else(|e| e.exit());
+    let mut config = Config::discover().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    if let Some(year) = cli.year {
+        config.year = year;
+    }
+    let year = config.year;
+    solvers::input::set_inputs_dir(config.inputs_dir.clone());
 
-    let use_example = matches!(cli.mode.as_str(), "example");
+    let isolation = cli.isolation(&config);
+    let recorder = cli.recorder(&config);
+    let answers_path = resolve(&cli.answers, &config, answers::DEFAULT_PATH);
 
-    let result = match solvers::run_day(cli.day, cli.part, use_example) {
-        Some(r) => r,
-        None => {
-            eprintln!("Day {} is not implemented yet", cli.day);
-            std::process::exit(0);
+    match cli.command {
+        Command::All { mode, jobs, variants } => {
+            let answers = load_answers(&answers_path, year);
+            let jobs = jobs.unwrap_or_else(pool::default_jobs);
+            let variants = VariantSelector::new(None, variants);
+            let options = all::Options {
+                mode: &mode,
+                variants: &variants,
+                jobs,
+                format: cli.format,
+                quiet: cli.quiet,
+            };
+            if !all::run(year, &options, &answers, &load_params(&config), &isolation, &recorder) {
+                std::process::exit(1);
+            }
+        }
+        Command::Verify { jobs } => {
+            let answers = load_answers(&answers_path, year);
+            let jobs = jobs.unwrap_or_else(pool::default_jobs);
+            if !verify::run(year, &answers, &load_params(&config), &isolation, jobs) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench { day, part, mode, warmup, iterations, variant, variants } => {
+            if let (DaySelector::Day(day), Some(name)) = (day, &variant) {
+                if let Err(e) = variants::check(year, day, part, name) {
+                    eprintln!("{}", e);
+                    std::process::exit(2);
+                }
+            }
+            let variants = VariantSelector::new(variant, variants);
+            let options = bench::Options {
+                variants: &variants,
+                use_example: mode == "example",
+                warmup,
+                iterations,
+            };
+            bench::run(year, day, part, &options, &load_params(&config))
+        }
+        Command::New { day, stdin } => {
+            if let Err(e) = scaffold::run(year, day, stdin, &answers_path, &config.root) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Watch { day, part, mode, interval } => watch::run(
+            year,
+            day,
+            part,
+            mode == "example",
+            &answers_path,
+            &config.root,
+            Duration::from_millis(interval),
+        ),
+        Command::Fetch { day, client } => {
+            if let Err(e) = client.client(&config).and_then(|mut c| fetch::run(day, &mut c)) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Submit { day, part, log, yes, client } => {
+            let log = resolve(&log, &config, guesses::DEFAULT_PATH);
+            let params = load_params(&config);
+            match client
+                .client(&config)
+                .and_then(|mut c| submit::run(day, part, &mut c, &log, &params, &isolation, yes))
+            {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Tui => {
+            let answers = load_answers(&answers_path, year);
+            if let Err(e) = tui::run(year, &answers, &load_params(&config), &isolation) {
+                eprintln!("Terminal error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Repl => {
+            let answers = load_answers(&answers_path, year);
+            repl::run(&config, &answers, &load_params(&config), &isolation);
+        }
+        Command::Serve { port, jobs } => {
+            let answers = load_answers(&answers_path, year);
+            let jobs = jobs.unwrap_or_else(pool::default_jobs);
+            if let Err(e) = serve::run(year, port, &answers, &load_params(&config), &isolation, jobs) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
         }
+        Command::Params { day } => {
+            if let Err(e) = params::run(&config, day) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Variants { day } => {
+            if let Err(e) = variants::run(year, day) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Diff { day, part, a, b, input, mode, out } => {
+            let mut start = load_params(&config).input(year, day, mode == "example");
+            if let Some(path) = input {
+                start = start.with_source(Source::Path(path));
+            }
+            if let Err(e) = diff::run(part, &a, &b, &start, out, &isolation) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Cache { action: CacheAction::Clear } => {
+            if let Err(e) = cache::clear(&config.resolve(cache::DEFAULT_DIR)) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::History { day } => {
+            if let Err(e) = history::run(&recorder.path, year, day) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Calendar { jobs } => {
+            let answers = load_answers(&answers_path, year);
+            let jobs = jobs.unwrap_or_else(pool::default_jobs);
+            calendar::run(year, &answers, &load_params(&config), &isolation, jobs);
+        }
+        Command::Readme { update, iterations, jobs } => {
+            let answers = load_answers(&answers_path, year);
+            let jobs = jobs.unwrap_or_else(pool::default_jobs);
+            let path = config.resolve("README.md");
+            let table = readme::table(year, &answers, &load_params(&config), &isolation, jobs, iterations);
+            if let Err(e) = readme::run(&table, &path, update) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Report { out, mask_answers, iterations, jobs } => {
+            let answers = load_answers(&answers_path, year);
+            let out = resolve(&out, &config, report::DEFAULT_PATH);
+            let options = report::Options {
+                out: &out,
+                jobs: jobs.unwrap_or_else(pool::default_jobs),
+                iterations,
+                mask_answers,
+            };
+            if let Err(e) = report::run(year, &config.root, &options, &answers, &load_params(&config), &isolation) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Run(args) => run_single(args, &config, &answers_path, &isolation, &recorder, cli.format, cli.quiet),
+    }
+}
+
+/// Name of the input kind, as used on the command line.
+fn mode_name(use_example: bool) -> &'static str {
+    if use_example {
+        "example"
+    } else {
+        "real"
+    }
+}
+
+fn load_answers(path: &Path, year: u32) -> Answers {
+    Answers::load(path, year).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    })
+}
+
+fn load_params(config: &Config) -> params::Configured {
+    params::Configured::load(config).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    })
+}
+
+fn run_single(
+    args: RunArgs,
+    config: &Config,
+    answers_path: &Path,
+    isolation: &Isolation,
+    recorder: &Recorder,
+    format: Format,
+    quiet: bool,
+) {
+    let (day, part) = (args.day, args.part);
+    let Some(mode) = args.mode.as_deref().or(config.mode(day)) else {
+        eprintln!("No input given: pass example or real, or set `mode` in {}", config::FILE_NAME);
+        std::process::exit(2);
     };
 
-    if let Some(exp) = cli.expected {
-        if result == exp {
-            println!("✅ Matches expected: {}", exp);
-        } else {
-            eprintln!("❌ Mismatch: expected {}, got {}", exp, result);
+    if let Some(name) = &args.variant {
+        if let Err(e) = variants::check(config.year, day, Some(part), name) {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
+
+    let use_example = matches!(mode, "example");
+    let run_params = load_params(config).resolve(day, use_example, &args.params);
+    if let Err(e) = params::check(config.year, day, &run_params) {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    }
+    let input = args.input(config.year, day, use_example).with_params(run_params);
+
+    let answers = load_answers(answers_path, config.year);
+    if !use_example && config.gate && !args.no_gate {
+        if let Err(report) = gate::check(part, &input, &answers, isolation) {
+            eprintln!("{}", report);
+            std::process::exit(1);
+        }
+    }
+
+    let expected = match args.expected.clone() {
+        Some(exp) => Some(exp),
+        // Registered answers only apply to the checked-in inputs and configured parameters.
+        None if input.source == Source::Conventional && args.params.is_empty() => {
+            answers.get(day, part, use_example).map(str::to_string)
         }
+        None => None,
+    };
+
+    let record = Record::run(part, &input, expected, isolation);
+    let no_regression = recorder.record(std::slice::from_ref(&record));
+
+    if quiet {
+        output::print_quiet(std::slice::from_ref(&record));
+    } else if format != Format::Text {
+        output::print_records(std::slice::from_ref(&record), format);
     } else {
-        println!("Result: {}", result);
+        match record.status() {
+            Status::Skipped => eprintln!("Day {} is not implemented yet", day),
+            Status::Pass => println!("✅ Matches expected: {}", record.expected.as_deref().unwrap_or_default()),
+            Status::Fail => eprintln!(
+                "❌ Mismatch: expected {}, got {}",
+                record.expected.as_deref().unwrap_or_default(),
+                record.answer.as_deref().unwrap_or_default()
+            ),
+            Status::Unchecked => println!("Result: {}", record.answer.as_deref().unwrap_or_default()),
+            // Already reported on stderr when the run failed.
+            Status::Panic | Status::Timeout => {}
+        }
+        if record.cached {
+            println!("(answer from the cache; --no-cache runs the solver)");
+        }
+        if let Some(mem) = record.mem {
+            println!(
+                "Memory: {} allocations, {} allocated, {} peak",
+                mem.allocations,
+                alloc::format_bytes(mem.bytes),
+                alloc::format_bytes(mem.peak)
+            );
+        }
+    }
+
+    if record.status().is_failure() || !no_regression {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One invocation of every subcommand, with its required arguments.
+    const COMMANDS: &[&[&str]] = &[
+        &["run", "1", "2", "real"],
+        &["1", "2", "real"],
+        &["all", "--mode", "real"],
+        &["verify"],
+        &["bench", "1"],
+        &["new", "9"],
+        &["watch", "1", "1", "example"],
+        &["fetch", "1"],
+        &["submit", "1", "1"],
+        &["tui"],
+        &["repl"],
+        &["serve"],
+        &["params", "8"],
+        &["variants", "2"],
+        &["diff", "2", "1", "--a", "default", "--b", "fast"],
+        &["cache", "clear"],
+        &["history", "1"],
+        &["calendar"],
+        &["readme"],
+        &["report"],
+    ];
+
+    /// Every global flag, with a value where it takes one.
+    const GLOBALS: &[&[&str]] = &[
+        &["--answers", "other.toml"],
+        &["--format", "json"],
+        &["--quiet"],
+        &["--timeout", "5"],
+        &["--repro-dir", "bundles"],
+        &["--history", "other-history.toml"],
+        &["--check-regression=10"],
+        &["--mem"],
+        &["--year", "2024"],
+        &["--no-cache"],
+        &["--no-history"],
+    ];
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_args(std::iter::once("runner").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn global_flags_parse_before_and_after_every_subcommand() {
+        for command in COMMANDS {
+            for flag in GLOBALS {
+                let before = [*flag, *command].concat();
+                let after = [*command, *flag].concat();
+                for args in [before, after] {
+                    if let Err(e) = parse(&args) {
+                        panic!("`runner {}` failed to parse:\n{}", args.join(" "), e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn global_flags_take_effect_in_either_position() {
+        let before = parse(&["--timeout", "5", "--year", "2024", "verify"]).unwrap();
+        let after = parse(&["verify", "--timeout", "5", "--year", "2024"]).unwrap();
+        for cli in [before, after] {
+            assert_eq!(cli.timeout, Some(5.0));
+            assert_eq!(cli.year, Some(2024));
+            assert!(matches!(cli.command, Command::Verify { .. }));
+        }
+
+        let cli = parse(&["--format", "json", "all", "--mode", "real"]).unwrap();
+        assert_eq!(cli.format, Format::Json);
+        assert!(matches!(cli.command, Command::All { ref mode, .. } if mode == "real"));
+    }
+
+    #[test]
+    fn check_regression_threshold() {
+        assert_eq!(parse(&["all"]).unwrap().check_regression, None);
+        assert_eq!(parse(&["all", "--check-regression"]).unwrap().check_regression, Some(25.0));
+        assert_eq!(parse(&["--check-regression=5", "all"]).unwrap().check_regression, Some(5.0));
+        // The percentage must be attached, so a following day is not taken for it.
+        let cli = parse(&["--check-regression", "1", "2"]).unwrap();
+        assert_eq!(cli.check_regression, Some(25.0));
+        assert!(matches!(cli.command, Command::Run(RunArgs { day: 1, part: 2, .. })));
+    }
+
+    #[test]
+    fn a_leading_day_is_a_single_run() {
+        let cli = parse(&["--no-cache", "8", "1", "example", "-p", "connections=5"]).unwrap();
+        assert!(cli.no_cache);
+        let Command::Run(args) = cli.command else {
+            panic!("expected a single run");
+        };
+        assert_eq!((args.day, args.part, args.mode.as_deref()), (8, 1, Some("example")));
+        assert_eq!(args.params, vec![("connections".to_string(), 5)]);
+    }
+
+    #[test]
+    fn mistyped_subcommands_are_not_taken_for_runs() {
+        let e = parse(&["verfy"]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidSubcommand);
+        let e = parse(&["1", "3"]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::ValueValidation);
+        // Option values are not days.
+        let e = parse(&["--timeout", "5", "verfy"]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidSubcommand);
+        let e = parse(&["--timeout=5", "1", "3"]).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::ValueValidation);
     }
 }
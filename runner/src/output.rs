@@ -0,0 +1,214 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::record::{Record, Status};
+
+/// How run results are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    Text,
+    /// A JSON array with one object per day/part
+    Json,
+    /// CSV with a header row and one row per day/part
+    Csv,
+    /// JUnit XML with one test case per day/part
+    Junit,
+}
+
+/// The JSON shape of one record, shared by `--format json` and `runner serve`.
+#[derive(Serialize)]
+pub struct JsonRecord<'a> {
+    day: u32,
+    // Only present for solution variants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'a str>,
+    part: Option<u8>,
+    mode: Option<&'a str>,
+    answer: Option<&'a str>,
+    expected: Option<&'a str>,
+    status: &'a str,
+    duration_ms: Option<f64>,
+    cpu_ms: Option<f64>,
+    error: Option<String>,
+    // Only present for answers from the result cache.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    cached: bool,
+    // Only present with `--mem`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alloc_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<u64>,
+}
+
+/// Print records in one of the machine-readable formats.
+///
+/// `Format::Text` is left to the caller, since single runs and `all` render
+/// text differently.
+pub fn print_records(records: &[Record], format: Format) {
+    match format {
+        Format::Text => unreachable!("text output is rendered by the caller"),
+        Format::Json => print_json(records),
+        Format::Csv => print_csv(records),
+        Format::Junit => print_junit(records),
+    }
+}
+
+/// Print only the bare answers, one per line, for use in shell pipelines.
+pub fn print_quiet(records: &[Record]) {
+    for answer in records.iter().filter_map(|r| r.answer.as_deref()) {
+        println!("{}", answer);
+    }
+}
+
+fn duration_ms(record: &Record) -> Option<f64> {
+    record.elapsed.map(|d| d.as_secs_f64() * 1000.0)
+}
+
+impl<'a> From<&'a Record> for JsonRecord<'a> {
+    fn from(r: &'a Record) -> Self {
+        JsonRecord {
+            day: r.day,
+            variant: r.variant.as_deref(),
+            part: r.part,
+            mode: r.mode,
+            answer: r.answer.as_deref(),
+            expected: r.expected.as_deref(),
+            status: r.status().as_str(),
+            duration_ms: duration_ms(r),
+            cpu_ms: r.cpu.map(|d| d.as_secs_f64() * 1000.0),
+            error: r.failure.as_ref().map(ToString::to_string),
+            cached: r.cached,
+            allocations: r.mem.map(|m| m.allocations),
+            alloc_bytes: r.mem.map(|m| m.bytes),
+            peak_bytes: r.mem.map(|m| m.peak),
+        }
+    }
+}
+
+fn print_json(records: &[Record]) {
+    let json: Vec<JsonRecord> = records.iter().map(JsonRecord::from).collect();
+
+    println!("{}", serde_json::to_string_pretty(&json).expect("records serialize to JSON"));
+}
+
+fn print_csv(records: &[Record]) {
+    // Variant and memory columns are only added when variants ran or with `--mem`.
+    let with_variant = records.iter().any(|r| r.variant.is_some());
+    let with_mem = records.iter().any(|r| r.mem.is_some());
+    print!("day,{}part,mode,answer,expected,status,duration_ms,cpu_ms,error", if with_variant { "variant," } else { "" });
+    println!("{}", if with_mem { ",allocations,alloc_bytes,peak_bytes" } else { "" });
+    for r in records {
+        print!("{},", r.day);
+        if with_variant {
+            print!("{},", r.variant.as_deref().unwrap_or(""));
+        }
+        print!(
+            "{},{},{},{},{},{},{},{}",
+            r.part.map_or(String::new(), |p| p.to_string()),
+            r.mode.unwrap_or(""),
+            csv_field(r.answer.as_deref().unwrap_or("")),
+            csv_field(r.expected.as_deref().unwrap_or("")),
+            r.status().as_str(),
+            duration_ms(r).map_or(String::new(), |ms| format!("{:.3}", ms)),
+            r.cpu.map_or(String::new(), |d| format!("{:.3}", d.as_secs_f64() * 1000.0)),
+            csv_field(&r.failure.as_ref().map_or(String::new(), ToString::to_string)),
+        );
+        match r.mem {
+            Some(m) => println!(",{},{},{}", m.allocations, m.bytes, m.peak),
+            None if with_mem => println!(",,,"),
+            None => println!(),
+        }
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print_junit(records: &[Record]) {
+    let count = |status| records.iter().filter(|r| r.status() == status).count();
+    let total_secs: f64 = records.iter().filter_map(|r| r.elapsed).map(|d| d.as_secs_f64()).sum();
+
+    println!(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    println!(
+        r#"<testsuite name="advent-of-code" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+        records.len(),
+        count(Status::Fail),
+        count(Status::Panic) + count(Status::Timeout),
+        count(Status::Skipped),
+        total_secs
+    );
+    for r in records {
+        let time = r.elapsed.map_or(0.0, |d| d.as_secs_f64());
+        println!(
+            r#"  <testcase classname="day{:02}" name="{}" time="{:.6}">"#,
+            r.day,
+            xml_escape(&r.name()),
+            time
+        );
+        match r.status() {
+            Status::Pass | Status::Unchecked => println!(
+                "    <system-out>{}</system-out>",
+                xml_escape(r.answer.as_deref().unwrap_or(""))
+            ),
+            Status::Fail => {
+                let message = format!(
+                    "expected {}, got {}",
+                    r.expected.as_deref().unwrap_or(""),
+                    r.answer.as_deref().unwrap_or("")
+                );
+                println!(r#"    <failure message="{}"/>"#, xml_escape(&message));
+            }
+            Status::Skipped => println!(r#"    <skipped message="not implemented"/>"#),
+            Status::Panic | Status::Timeout => {
+                let message = r.failure.as_ref().map_or(String::new(), ToString::to_string);
+                println!(r#"    <error message="{}"/>"#, xml_escape(&message));
+            }
+        }
+        println!("  </testcase>");
+    }
+    println!("</testsuite>");
+}
+
+pub fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line 1\nline 2"), "\"line 1\nline 2\"");
+        assert_eq!(csv_field("line 1\r\nline 2"), "\"line 1\r\nline 2\"");
+    }
+
+    #[test]
+    fn xml_special_characters_are_escaped() {
+        assert_eq!(xml_escape("day 1 part 2"), "day 1 part 2");
+        assert_eq!(
+            xml_escape(r#"expected <a & b>, got "c" 'd'"#),
+            "expected &lt;a &amp; b&gt;, got &quot;c&quot; &apos;d&apos;"
+        );
+        // Text that looks like an entity stays text.
+        assert_eq!(xml_escape("&lt;"), "&amp;lt;");
+    }
+}
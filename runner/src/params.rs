@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use solvers::{
+    params::{metadata_path, read_metadata},
+    Input,
+};
+
+use crate::config::{self, Config};
+
+/// Check that every parameter in `params` is declared by `day` and within
+/// its declared bounds, taking the defaults for parameters it leaves out.
+pub fn check(year: u32, day: u32, params: &BTreeMap<String, i64>) -> Result<(), String> {
+    let declared = solvers::params(year, day);
+    let value_of = |name: &str| match params.get(name) {
+        Some(&value) => value,
+        None => declared.iter().find(|p| p.name == name).map_or(i64::MAX, |p| p.default),
+    };
+    for (name, &value) in params {
+        if let Some(param) = declared.iter().find(|p| p.name == name) {
+            param.check(value, value_of).map_err(|e| format!("Day {}: {}", day, e))?;
+            continue;
+        }
+        let names: Vec<&str> = declared.iter().map(|p| p.name).collect();
+        return Err(if names.is_empty() {
+            format!("Day {} has no parameters", day)
+        } else {
+            format!("Day {} has no parameter '{}'; available: {}", day, name, names.join(", "))
+        });
+    }
+    Ok(())
+}
+
+/// The `[dayNN] params` tables of aoc.toml, checked against the days'
+/// declarations, for every run of a conventional input.
+#[derive(Clone, Debug, Default)]
+pub struct Configured {
+    days: BTreeMap<u32, BTreeMap<String, i64>>,
+}
+
+impl Configured {
+    /// Collect the configured parameters, rejecting any that the day does not
+    /// declare or that are out of bounds.
+    pub fn load(config: &Config) -> Result<Self, String> {
+        let mut days = BTreeMap::new();
+        for day in config.param_days() {
+            let params = config.params(day);
+            check(config.year, day, &params).map_err(|e| format!("{} in {}", e, config::FILE_NAME))?;
+            days.insert(day, params);
+        }
+        Ok(Configured { days })
+    }
+
+    /// The parameters of a run: the configured ones for real inputs
+    /// (examples take theirs from their metadata file), then `given`.
+    pub fn resolve(&self, day: u32, use_example: bool, given: &[(String, i64)]) -> BTreeMap<String, i64> {
+        let mut params = BTreeMap::new();
+        if !use_example {
+            params.extend(self.days.get(&day).cloned().unwrap_or_default());
+        }
+        params.extend(given.iter().cloned());
+        params
+    }
+
+    /// The conventional input of a day/mode with its configured parameters.
+    pub fn input(&self, year: u32, day: u32, use_example: bool) -> Input {
+        Input::conventional(year, day, use_example).with_params(self.resolve(day, use_example, &[]))
+    }
+}
+
+/// List the parameters a day declares, with their bounds and their default,
+/// example and configured values.
+pub fn run(config: &Config, day: u32) -> Result<(), String> {
+    let year = config.year;
+    if !solvers::implemented_days(year).contains(&day) {
+        return Err(format!("Day {} is not implemented yet", day));
+    }
+    let declared = solvers::params(year, day);
+    if declared.is_empty() {
+        println!("Day {} has no parameters", day);
+        return Ok(());
+    }
+
+    let metadata_path = metadata_path(&solvers::input::conventional_path(year, day, true));
+    let example: BTreeMap<String, i64> = read_metadata(&metadata_path).into_iter().collect();
+    let configured = config.params(day);
+
+    let value = |map: &BTreeMap<String, i64>, name: &str| map.get(name).map_or("-".to_string(), i64::to_string);
+    println!(
+        "  {:<16}  {:>16}  {:>10}  {:>10}  {:>10}  Description",
+        "Name",
+        "Range",
+        "Default",
+        "Example",
+        config::FILE_NAME
+    );
+    for param in declared {
+        println!(
+            "  {:<16}  {:>16}  {:>10}  {:>10}  {:>10}  {}",
+            param.name,
+            match param.below {
+                Some(other) => format!("{}..{}", param.min, other),
+                None => format!("{}..={}", param.min, param.max),
+            },
+            param.default,
+            value(&example, param.name),
+            value(&configured, param.name),
+            param.help
+        );
+    }
+    println!();
+    println!("Example values are read from {}", metadata_path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(values: &[(&str, i64)]) -> BTreeMap<String, i64> {
+        values.iter().map(|&(name, value)| (name.to_string(), value)).collect()
+    }
+
+    #[test]
+    fn values_within_bounds_pass() {
+        let year = solvers::DEFAULT_YEAR;
+        assert_eq!(check(year, 1, &params(&[("dial_size", 1), ("start", 0)])), Ok(()));
+        assert_eq!(check(year, 3, &params(&[("part1_digits", 19)])), Ok(()));
+        assert_eq!(check(year, 8, &params(&[])), Ok(()));
+    }
+
+    #[test]
+    fn values_outside_bounds_are_rejected() {
+        let year = solvers::DEFAULT_YEAR;
+        assert_eq!(
+            check(year, 1, &params(&[("dial_size", 0)])).unwrap_err(),
+            "Day 1: parameter dial_size must be between 1 and 1000000000, got 0"
+        );
+        assert!(check(year, 1, &params(&[("start", -1)])).is_err());
+        assert_eq!(
+            check(year, 1, &params(&[("start", 100)])).unwrap_err(),
+            "Day 1: parameter start must be between 0 and 99 (below dial_size), got 100"
+        );
+        assert_eq!(
+            check(year, 1, &params(&[("dial_size", 10), ("start", 10)])).unwrap_err(),
+            "Day 1: parameter start must be between 0 and 9 (below dial_size), got 10"
+        );
+        assert_eq!(check(year, 1, &params(&[("dial_size", 1000), ("start", 100)])), Ok(()));
+        assert!(check(year, 3, &params(&[("part2_digits", 20)])).is_err());
+        assert!(check(year, 8, &params(&[("connections", i64::MAX)])).is_err());
+    }
+
+    #[test]
+    fn undeclared_names_are_rejected() {
+        let year = solvers::DEFAULT_YEAR;
+        assert_eq!(
+            check(year, 8, &params(&[("conections", 10)])).unwrap_err(),
+            "Day 8 has no parameter 'conections'; available: connections"
+        );
+        assert_eq!(check(year, 2, &params(&[("x", 1)])).unwrap_err(), "Day 2 has no parameters");
+    }
+
+    #[test]
+    fn configured_values_apply_to_real_inputs_only() {
+        let configured = Configured {
+            days: BTreeMap::from([(8, params(&[("connections", 10)]))]),
+        };
+        assert_eq!(configured.resolve(8, false, &[]), params(&[("connections", 10)]));
+        assert_eq!(configured.resolve(8, true, &[]), params(&[]));
+        assert_eq!(configured.resolve(1, false, &[]), params(&[]));
+        // The command line wins.
+        let given = [("connections".to_string(), 5)];
+        assert_eq!(configured.resolve(8, false, &given), params(&[("connections", 5)]));
+        assert_eq!(configured.resolve(8, true, &given), params(&[("connections", 5)]));
+
+        let input = configured.input(solvers::DEFAULT_YEAR, 8, false);
+        assert_eq!(input.params, params(&[("connections", 10)]));
+    }
+}
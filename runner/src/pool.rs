@@ -0,0 +1,44 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Number of workers to use when `--jobs` is not given.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Run `f` over `jobs` on up to `workers` threads and return the results in
+/// job order, regardless of which job finished first.
+pub fn run_ordered<T, R, F>(jobs: &[T], workers: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..jobs.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                let result = f(job);
+                results.lock().expect("no worker panics while holding the lock")[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("no worker panics while holding the lock")
+        .into_iter()
+        .map(|r| r.expect("every job ran"))
+        .collect()
+}
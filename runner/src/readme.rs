@@ -0,0 +1,140 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::{
+    answers::Answers,
+    bench,
+    calendar::{self, DayProgress},
+    isolate::Isolation,
+    params, LAST_DAY,
+};
+
+/// Markers delimiting the generated section of the README.
+const START_MARKER: &str = "<!-- results:start -->";
+const END_MARKER: &str = "<!-- results:end -->";
+
+/// Build a Markdown table of every implemented day: title, verified parts
+/// and the median runtime over `iterations` benchmark runs on the real input.
+pub fn table(
+    year: u32,
+    answers: &Answers,
+    params: &params::Configured,
+    isolation: &Isolation,
+    jobs: usize,
+    iterations: u32,
+) -> String {
+    let progress = calendar::collect(year, answers, params, isolation, jobs);
+
+    let mut table = String::new();
+    table.push_str("| Day | Title | Parts | Median runtime |\n");
+    table.push_str("|---:|---|:---:|---:|\n");
+
+    let mut total = Duration::ZERO;
+    for p in progress.iter().filter(|p| !p.parts.is_empty()) {
+        let median = median_runtime(year, p, params, iterations);
+        total += median.unwrap_or_default();
+        table.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            p.day,
+            solvers::title(year, p.day).unwrap_or("-"),
+            p.marks(),
+            median.map_or("-".to_string(), |d| format!("{:.2?}", d))
+        ));
+    }
+
+    let stars: usize = progress.iter().map(DayProgress::stars).sum();
+    table.push_str(&format!(
+        "| **Total** | | **{}/{} ★** | **{:.2?}** |\n",
+        stars,
+        LAST_DAY * 2,
+        total
+    ));
+    table
+}
+
+/// Sum of the median runtimes of a day's solved parts (see
+/// [`bench::solved_median`]), or `None` if none was benchmarked.
+fn median_runtime(year: u32, progress: &DayProgress, params: &params::Configured, iterations: u32) -> Option<Duration> {
+    let medians: Vec<Duration> = progress
+        .parts
+        .iter()
+        .filter_map(|r| bench::solved_median(year, r, params, iterations))
+        .collect();
+    (!medians.is_empty()).then(|| medians.into_iter().sum())
+}
+
+/// Print the results `table`, or with `update` write it between the result
+/// markers in the README at `path`.
+pub fn run(table: &str, path: &Path, update: bool) -> Result<(), String> {
+    if !update {
+        print!("{}", table);
+        return Ok(());
+    }
+
+    let readme = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let missing = || {
+        format!(
+            "{} has no generated section; add `{}` and `{}` lines where the table should go",
+            path.display(),
+            START_MARKER,
+            END_MARKER
+        )
+    };
+    let (before, rest) = readme.split_once(START_MARKER).ok_or_else(missing)?;
+    let (_, after) = rest.split_once(END_MARKER).ok_or_else(missing)?;
+
+    let updated = format!("{}{}\n{}{}{}", before, START_MARKER, table, END_MARKER, after);
+    if updated == readme {
+        println!("{} is up to date", path.display());
+        return Ok(());
+    }
+    fs::write(path, updated).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    println!("Updated the results table in {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::test_support::scratch_dir;
+
+    const TABLE: &str = "| Day |\n|---:|\n| 1 |\n";
+
+    fn readme(name: &str, text: &str) -> PathBuf {
+        let path = scratch_dir(name).join("README.md");
+        fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn only_the_generated_section_is_rewritten() {
+        let path = readme(
+            "readme-rewrite",
+            &format!("# Title\n\nIntro\n{}\nold table\n{}\n\nOutro\n", START_MARKER, END_MARKER),
+        );
+        run(TABLE, &path, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("# Title\n\nIntro\n{}\n{}{}\n\nOutro\n", START_MARKER, TABLE, END_MARKER)
+        );
+    }
+
+    #[test]
+    fn rewriting_twice_changes_nothing() {
+        let path = readme("readme-twice", &format!("{}\n{}\n", START_MARKER, END_MARKER));
+        run(TABLE, &path, true).unwrap();
+        let once = fs::read_to_string(&path).unwrap();
+        run(TABLE, &path, true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), once);
+    }
+
+    #[test]
+    fn a_missing_end_marker_is_an_error() {
+        let text = format!("Intro\n{}\nold table\n", START_MARKER);
+        let path = readme("readme-unterminated", &text);
+        let e = run(TABLE, &path, true).unwrap_err();
+        assert!(e.contains("has no generated section"), "{}", e);
+        assert_eq!(fs::read_to_string(&path).unwrap(), text);
+    }
+}
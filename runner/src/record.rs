@@ -0,0 +1,221 @@
+use std::time::{Duration, Instant};
+
+use solvers::{Input, Source};
+
+use crate::{
+    alloc::MemStats,
+    answers::Answers,
+    cache,
+    hash,
+    isolate::{self, Failure, Isolation},
+    mode_name, params,
+};
+
+/// How a result compares to its expected answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Matches the expected answer.
+    Pass,
+    /// Differs from the expected answer.
+    Fail,
+    /// Solved, but no expected answer is known.
+    Unchecked,
+    /// The day is not implemented.
+    Skipped,
+    /// The solver panicked.
+    Panic,
+    /// The solver did not finish in time.
+    Timeout,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unchecked => "unchecked",
+            Status::Skipped => "skipped",
+            Status::Panic => "panic",
+            Status::Timeout => "timeout",
+        }
+    }
+
+    /// Whether this status should make the runner exit non-zero.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Fail | Status::Panic | Status::Timeout)
+    }
+}
+
+/// See [`Record::input_hash`].
+fn input_hash(input: &Input) -> Option<String> {
+    // Stdin can only be read once; the runner buffers it into text up front.
+    match input.source {
+        Source::Stdin => None,
+        _ => input.read().ok().map(|text| hash::input_digest(&text, input)),
+    }
+}
+
+/// The result of running (or skipping) one day/part on one input.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub day: u32,
+    /// `None` for a skipped day, which covers both parts.
+    pub part: Option<u8>,
+    /// `None` for a skipped day, which covers both inputs.
+    pub mode: Option<&'static str>,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub elapsed: Option<Duration>,
+    /// CPU time of the solver's thread, where the platform reports it.
+    pub cpu: Option<Duration>,
+    /// Set when the solver panicked or timed out.
+    pub failure: Option<Failure>,
+    /// FNV-1a digest of the input text and parameters, when it could be read.
+    pub input_hash: Option<String>,
+    /// Allocation counters, when memory tracking is on.
+    pub mem: Option<MemStats>,
+    /// The solution variant that ran, or `None` for the day's main module.
+    pub variant: Option<String>,
+    /// The answer came from the result cache; `elapsed` and `cpu` are those
+    /// of the run that stored it.
+    pub cached: bool,
+}
+
+impl Record {
+    /// Run a day/part on `input` in isolation and compare it against
+    /// `expected`. Panics and timeouts are recorded, and a repro bundle is
+    /// written for them.
+    ///
+    /// With a result cache, a stored answer for the same code and input is
+    /// returned without running the solver, and new answers are stored.
+    pub fn run(part: u8, input: &Input, expected: Option<String>, isolation: &Isolation) -> Self {
+        let cache = isolation
+            .cache
+            .as_deref()
+            .and_then(|cache| Some((cache, cache::input_key(input)?)));
+        if let Some(entry) = cache.as_ref().and_then(|(cache, key)| cache.get(part, input, key)) {
+            return Self::from_cache(part, input, expected, entry);
+        }
+
+        let start = Instant::now();
+        let result = isolate::run(part, input, isolation);
+        let elapsed = start.elapsed();
+
+        let (answer, elapsed, cpu, mem, failure) = match result {
+            Ok(Some(solved)) => (Some(solved.answer), Some(elapsed), solved.cpu, solved.mem, None),
+            Ok(None) => (None, None, None, None, None),
+            Err(failure) => {
+                eprintln!(
+                    "💥 Day {} part {} ({}) {}",
+                    input.day,
+                    part,
+                    mode_name(input.example),
+                    failure
+                );
+                match isolate::write_repro(&isolation.repro_dir, part, input, &failure) {
+                    Ok(bundle) => eprintln!("   repro bundle written to {}", bundle.display()),
+                    Err(e) => eprintln!("   {}", e),
+                }
+                (None, Some(elapsed), None, None, Some(failure))
+            }
+        };
+
+        if let (Some((cache, key)), Some(answer), Some(elapsed)) = (cache, &answer, elapsed) {
+            if let Err(e) = cache.insert(part, input, key, answer.clone(), elapsed, cpu) {
+                eprintln!("{}", e);
+            }
+        }
+
+        Record {
+            day: input.day,
+            part: Some(part),
+            mode: Some(mode_name(input.example)),
+            answer,
+            expected,
+            elapsed,
+            cpu,
+            failure,
+            input_hash: input_hash(input),
+            mem,
+            variant: input.variant.clone(),
+            cached: false,
+        }
+    }
+
+    fn from_cache(part: u8, input: &Input, expected: Option<String>, entry: cache::Entry) -> Self {
+        Record {
+            day: input.day,
+            part: Some(part),
+            mode: Some(mode_name(input.example)),
+            answer: Some(entry.answer),
+            expected,
+            elapsed: Some(Duration::from_secs_f64(entry.duration_ms / 1000.0)),
+            cpu: entry.cpu_ms.map(|ms| Duration::from_secs_f64(ms / 1000.0)),
+            failure: None,
+            input_hash: input_hash(input),
+            mem: None,
+            variant: input.variant.clone(),
+            cached: true,
+        }
+    }
+
+    /// Run a day/part on its conventional input, with its configured
+    /// parameters, and compare it against the registered answer, if any.
+    pub fn run_checked(
+        year: u32,
+        day: u32,
+        part: u8,
+        use_example: bool,
+        answers: &Answers,
+        params: &params::Configured,
+        isolation: &Isolation,
+    ) -> Self {
+        let expected = answers.get(day, part, use_example).map(str::to_string);
+        Self::run(part, &params.input(year, day, use_example), expected, isolation)
+    }
+
+    /// A placeholder for a day without a solution module.
+    pub fn skipped(day: u32) -> Self {
+        Record {
+            day,
+            part: None,
+            mode: None,
+            answer: None,
+            expected: None,
+            elapsed: None,
+            cpu: None,
+            failure: None,
+            input_hash: None,
+            mem: None,
+            variant: None,
+            cached: false,
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        match &self.failure {
+            Some(Failure::Panic(_)) => return Status::Panic,
+            Some(Failure::Timeout(_)) => return Status::Timeout,
+            None => {}
+        }
+        match (&self.answer, &self.expected) {
+            (None, _) => Status::Skipped,
+            (Some(_), None) => Status::Unchecked,
+            (Some(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Some(_), Some(_)) => Status::Fail,
+        }
+    }
+
+    /// A short `dayNN.partN.mode` name, used as a test case name. Variants
+    /// are named after their module, as `dayNN_variant.partN.mode`.
+    pub fn name(&self) -> String {
+        let module = match &self.variant {
+            Some(variant) => format!("day{:02}_{}", self.day, variant),
+            None => format!("day{:02}", self.day),
+        };
+        match (self.part, self.mode) {
+            (Some(part), Some(mode)) => format!("{}.part{}.{}", module, part, mode),
+            _ => module,
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::PathBuf,
+};
+
+use solvers::Source;
+
+use crate::{
+    alloc,
+    answers::Answers,
+    config::{self, Config},
+    isolate::Isolation,
+    mode_name, params,
+    record::{Record, Status},
+    LAST_DAY,
+};
+
+const HELP: &str = "\
+Commands:
+  run <day> <part> [example|real]  solve a part (mode defaults to `set mode` or aoc.toml)
+  input <day> [example|real]       show a day's input file
+  input                            show the file chosen with `set input`
+  set input <path>                 run on this file instead of inputs/
+  set input                        go back to the conventional inputs
+  set mode <example|real>          default input for `run`
+  set                              show the current settings
+  time                             timings of the last run
+  diff                             compare the last two answers
+  days                             list implemented days
+  help                             show this help
+  quit                             leave (or Ctrl-D)";
+
+/// State kept between REPL commands.
+struct Session<'a> {
+    config: &'a Config,
+    answers: &'a Answers,
+    params: &'a params::Configured,
+    isolation: &'a Isolation,
+    /// Input file chosen with `set input`, used instead of the conventional one.
+    input: Option<PathBuf>,
+    /// Default input kind chosen with `set mode`.
+    mode: Option<bool>,
+    /// The last two runs, newest last.
+    runs: Vec<Record>,
+}
+
+/// Read commands from stdin until `quit` or end of input. The solvers stay
+/// loaded in this process, so each command runs without a rebuild.
+pub fn run(config: &Config, answers: &Answers, params: &params::Configured, isolation: &Isolation) {
+    let mut session = Session {
+        config,
+        answers,
+        params,
+        isolation,
+        input: None,
+        mode: None,
+        runs: Vec::new(),
+    };
+
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!("Advent of Code {} REPL; type `help` for commands", config.year);
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            print!("aoc> ");
+            let _ = io::stdout().flush();
+        }
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(e)) => {
+                eprintln!("Failed to read <stdin>: {}", e);
+                break;
+            }
+            None => break,
+        };
+
+        let line = line.trim();
+        if matches!(line, "quit" | "exit") {
+            break;
+        }
+        if let Err(e) = session.execute(line) {
+            eprintln!("{}", e);
+        }
+    }
+    if interactive {
+        println!();
+    }
+}
+
+impl Session<'_> {
+    fn execute(&mut self, line: &str) -> Result<(), String> {
+        let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+        let args: Vec<&str> = rest.split_whitespace().collect();
+
+        match command {
+            "" => Ok(()),
+            "run" => self.run(&args),
+            "input" => self.show_input(&args),
+            "set" => self.set(rest),
+            "time" => self.time(),
+            "diff" => self.diff(),
+            "days" => {
+                let days = solvers::implemented_days(self.config.year);
+                let days: Vec<String> = days.iter().map(|d| d.to_string()).collect();
+                println!("{}", days.join(" "));
+                Ok(())
+            }
+            "help" => {
+                println!("{}", HELP);
+                Ok(())
+            }
+            _ => Err(format!("Unknown command '{}'; type `help` for commands", command)),
+        }
+    }
+
+    fn run(&mut self, args: &[&str]) -> Result<(), String> {
+        let [day, part, rest @ ..] = args else {
+            return Err("Usage: run <day> <part> [example|real]".to_string());
+        };
+        let day = parse_day(day)?;
+        let part = match *part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(format!("expected part 1 or 2, got '{}'", part)),
+        };
+        let use_example = match rest {
+            [mode] => parse_mode(mode)?,
+            [] => self.default_mode(day)?,
+            _ => return Err("Usage: run <day> <part> [example|real]".to_string()),
+        };
+
+        let year = self.config.year;
+        let mut input = self.params.input(year, day, use_example);
+        if let Some(path) = &self.input {
+            input = input.with_source(Source::Path(path.clone()));
+        }
+        // Registered answers only apply to the checked-in inputs.
+        let expected = match input.source {
+            Source::Conventional => self.answers.get(day, part, use_example).map(str::to_string),
+            _ => None,
+        };
+
+        let record = Record::run(part, &input, expected, self.isolation);
+        let answer = record.answer.as_deref().unwrap_or_default();
+        let elapsed = match record.elapsed {
+            Some(d) if record.cached => format!("{:.2?}, cached", d),
+            Some(d) => format!("{:.2?}", d),
+            None => "-".to_string(),
+        };
+        match record.status() {
+            Status::Skipped => return Err(format!("Day {} is not implemented yet", day)),
+            Status::Pass => println!("✅ {}  ({})", answer, elapsed),
+            Status::Fail => println!(
+                "❌ {}  ({}), expected {}",
+                answer,
+                elapsed,
+                record.expected.as_deref().unwrap_or_default()
+            ),
+            Status::Unchecked => println!("{}  ({})", answer, elapsed),
+            // Already reported on stderr by `Record::run`.
+            Status::Panic | Status::Timeout => {}
+        }
+
+        if self.runs.len() == 2 {
+            self.runs.remove(0);
+        }
+        self.runs.push(record);
+        Ok(())
+    }
+
+    fn default_mode(&self, day: u32) -> Result<bool, String> {
+        if let Some(use_example) = self.mode {
+            return Ok(use_example);
+        }
+        match self.config.mode(day) {
+            Some(mode) => parse_mode(mode),
+            None => Err(format!(
+                "No input given: pass example or real, `set mode`, or set `mode` in {}",
+                config::FILE_NAME
+            )),
+        }
+    }
+
+    fn show_input(&self, args: &[&str]) -> Result<(), String> {
+        let path = match args {
+            [] => self
+                .input
+                .clone()
+                .ok_or("No input file set; use `input <day> [example|real]` or `set input <path>`")?,
+            [day, rest @ ..] => {
+                let day = parse_day(day)?;
+                let use_example = match rest {
+                    [mode] => parse_mode(mode)?,
+                    [] => self.default_mode(day)?,
+                    _ => return Err("Usage: input <day> [example|real]".to_string()),
+                };
+                solvers::input::conventional_path(self.config.year, day, use_example)
+            }
+        };
+
+        let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        println!("── {} ({} lines)", path.display(), text.lines().count());
+        print!("{}", text);
+        if !text.is_empty() && !text.ends_with('\n') {
+            println!();
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, rest: &str) -> Result<(), String> {
+        let (setting, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let value = value.trim();
+        match setting {
+            "" => {
+                let input = self.input.as_ref().map_or("conventional".to_string(), |p| p.display().to_string());
+                let mode = self.mode.map_or("from aoc.toml", mode_name);
+                println!("year  {}", self.config.year);
+                println!("input {}", input);
+                println!("mode  {}", mode);
+            }
+            "input" if value.is_empty() => self.input = None,
+            "input" => {
+                let path = PathBuf::from(value);
+                if !path.is_file() {
+                    return Err(format!("{} is not a file", path.display()));
+                }
+                self.input = Some(path);
+            }
+            "mode" => self.mode = Some(parse_mode(value)?),
+            _ => return Err(format!("Unknown setting '{}'; expected input or mode", setting)),
+        }
+        Ok(())
+    }
+
+    fn time(&self) -> Result<(), String> {
+        let record = self.runs.last().ok_or("Nothing has been run yet")?;
+        let format = |d: Option<std::time::Duration>| d.map_or("-".to_string(), |d| format!("{:.2?}", d));
+        println!("{}: {} wall, {} cpu", record.name(), format(record.elapsed), format(record.cpu));
+        if let Some(mem) = record.mem {
+            println!(
+                "Memory: {} allocations, {} allocated, {} peak",
+                mem.allocations,
+                alloc::format_bytes(mem.bytes),
+                alloc::format_bytes(mem.peak)
+            );
+        }
+        Ok(())
+    }
+
+    fn diff(&self) -> Result<(), String> {
+        let [previous, last] = self.runs.as_slice() else {
+            return Err("Need two runs to compare".to_string());
+        };
+        for record in [previous, last] {
+            println!(
+                "  {:<19}  input {:<16}  {}",
+                record.name(),
+                record.input_hash.as_deref().unwrap_or("-"),
+                record.answer.as_deref().unwrap_or("(no answer)")
+            );
+        }
+        if previous.answer == last.answer {
+            println!("Same answer");
+        } else {
+            println!("Answers differ");
+        }
+        Ok(())
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.parse() {
+        Ok(day) if (1..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(format!("expected a day from 1 to {}, got '{}'", LAST_DAY, s)),
+    }
+}
+
+fn parse_mode(s: &str) -> Result<bool, String> {
+    match s {
+        "example" => Ok(true),
+        "real" => Ok(false),
+        _ => Err(format!("expected example or real, got '{}'", s)),
+    }
+}
@@ -0,0 +1,320 @@
+use std::{fmt::Write, fs, path::Path, time::Duration};
+
+use crate::{
+    answers::Answers,
+    bench,
+    history,
+    isolate::Isolation,
+    output::xml_escape,
+    params, pool,
+    record::{Record, Status},
+    LAST_DAY,
+};
+
+/// Default location of the report, relative to the repository root.
+pub const DEFAULT_PATH: &str = "report.html";
+
+/// Shown instead of a real answer with `--mask-answers`.
+const MASK: &str = "••••••";
+
+/// The chart's time axis starts here; faster parts get a minimal bar.
+const AXIS_START: Duration = Duration::from_micros(1);
+
+const STYLE: &str = "\
+body { font: 15px/1.5 system-ui, sans-serif; max-width: 960px; margin: 2em auto; padding: 0 1em; color: #1f2328; }
+h1 { margin-bottom: 0; }
+.meta { color: #59636e; margin-top: 0.2em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.3em 0.6em; border-bottom: 1px solid #d1d9e0; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+code, .answer { font-family: ui-monospace, monospace; font-size: 0.9em; }
+code { background: #eff2f5; padding: 0.1em 0.3em; border-radius: 4px; }
+.pass { color: #1a7f37; }
+.fail, .panic, .timeout { color: #cf222e; }
+.unchecked { color: #9a6700; }
+.skipped, .none { color: #59636e; }
+section { border-top: 1px solid #d1d9e0; margin-top: 1.5em; }
+svg text { font: 12px system-ui, sans-serif; fill: #1f2328; }
+";
+
+/// One day/part: its example and real runs, and the median runtime on the
+/// real input if it was benchmarked.
+struct PartReport {
+    day: u32,
+    part: u8,
+    example: Record,
+    real: Record,
+    median: Option<Duration>,
+}
+
+/// How to build a report.
+pub struct Options<'a> {
+    pub out: &'a Path,
+    /// Number of worker threads for running the parts.
+    pub jobs: usize,
+    /// Timed runs per part for the median runtime.
+    pub iterations: u32,
+    /// Leave out real answers, so the report can be shared.
+    pub mask_answers: bool,
+}
+
+/// Run every implemented day/part on both inputs, benchmark the solved real
+/// inputs and write a self-contained HTML report: results, a runtime chart
+/// and the doc comment of each part. `root` is the repository whose commit
+/// the report names.
+pub fn run(
+    year: u32,
+    root: &Path,
+    options: &Options,
+    answers: &Answers,
+    params: &params::Configured,
+    isolation: &Isolation,
+) -> Result<(), String> {
+    let Options {
+        out,
+        jobs,
+        iterations,
+        mask_answers: mask,
+    } = *options;
+    let work: Vec<(u32, u8, bool)> = solvers::implemented_days(year)
+        .iter()
+        .flat_map(|&day| [(day, 1, true), (day, 1, false), (day, 2, true), (day, 2, false)])
+        .collect();
+    let mut records = pool::run_ordered(&work, jobs, |&(day, part, use_example)| {
+        Record::run_checked(year, day, part, use_example, answers, params, isolation)
+    })
+    .into_iter();
+
+    let mut parts = Vec::new();
+    while let (Some(example), Some(real)) = (records.next(), records.next()) {
+        let (day, part) = (real.day, real.part.expect("implemented days have parts"));
+        let median = bench::solved_median(year, &real, params, iterations);
+        parts.push(PartReport {
+            day,
+            part,
+            example,
+            real,
+            median,
+        });
+    }
+
+    let html = render(year, &parts, iterations, mask, history::git_commit(root));
+    fs::write(out, html).map_err(|e| format!("Failed to write {}: {}", out.display(), e))?;
+    println!("Wrote {}", out.display());
+    Ok(())
+}
+
+fn render(year: u32, parts: &[PartReport], iterations: u32, mask: bool, commit: Option<String>) -> String {
+    let mut html = String::new();
+    let title = format!("Advent of Code {}", year);
+    let _ = writeln!(html, "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">");
+    let _ = writeln!(html, "<title>{} report</title>", title);
+    let _ = writeln!(html, "<style>\n{}</style>\n</head>\n<body>", STYLE);
+
+    let stars = parts.iter().filter(|p| p.real.status() == Status::Pass).count();
+    let failing = parts
+        .iter()
+        .filter(|p| p.example.status().is_failure() || p.real.status().is_failure())
+        .count();
+    let _ = writeln!(html, "<h1>{}</h1>", title);
+    let _ = write!(
+        html,
+        "<p class=\"meta\">{}/{} ★ · {} part(s) failing · median of {} run(s) on the real input",
+        stars,
+        LAST_DAY * 2,
+        failing,
+        iterations
+    );
+    if let Some(commit) = commit {
+        let _ = write!(html, " · commit <code>{}</code>", xml_escape(&commit[..commit.len().min(10)]));
+    }
+    let _ = writeln!(html, "</p>");
+
+    let _ = writeln!(html, "<h2>Runtimes</h2>");
+    let _ = write!(html, "{}", chart(parts));
+
+    let _ = writeln!(html, "<h2>Results</h2>\n<table>");
+    let _ = writeln!(
+        html,
+        "<tr><th>Day</th><th>Title</th><th>Part</th><th>Example</th><th>Real</th><th>Median</th></tr>"
+    );
+    for p in parts {
+        let title = solvers::title(year, p.day).unwrap_or("");
+        let _ = writeln!(
+            html,
+            "<tr><td class=\"num\"><a href=\"#day{:02}\">{}</a></td><td>{}</td><td class=\"num\">{}</td><td>{}</td><td>{}</td><td class=\"num\">{}</td></tr>",
+            p.day,
+            p.day,
+            xml_escape(title),
+            p.part,
+            result_cell(&p.example, false),
+            result_cell(&p.real, mask),
+            p.median.map_or("-".to_string(), |d| format!("{:.2?}", d))
+        );
+    }
+    let _ = writeln!(html, "</table>");
+
+    let _ = writeln!(html, "<h2>Solutions</h2>");
+    for day in solvers::implemented_days(year) {
+        let _ = writeln!(html, "<section id=\"day{:02}\">", day);
+        let _ = match solvers::title(year, *day) {
+            Some(title) => writeln!(html, "<h3>Day {}: {}</h3>", day, xml_escape(title)),
+            None => writeln!(html, "<h3>Day {}</h3>", day),
+        };
+        for part in [1, 2] {
+            let _ = writeln!(html, "<h4>Part {}</h4>", part);
+            let _ = match solvers::part_doc(year, *day, part) {
+                Some(doc) => write!(html, "{}", doc_html(doc)),
+                None => writeln!(html, "<p class=\"none\">No doc comment.</p>"),
+            };
+        }
+        let _ = writeln!(html, "</section>");
+    }
+
+    let _ = writeln!(html, "</body>\n</html>");
+    html
+}
+
+/// An answer with its status, e.g. `✓ 1234`; failures carry their message
+/// as a tooltip.
+fn result_cell(record: &Record, mask: bool) -> String {
+    let status = record.status();
+    let mark = match status {
+        Status::Pass => "✓",
+        Status::Unchecked => "?",
+        Status::Fail | Status::Panic | Status::Timeout => "✗",
+        Status::Skipped => "·",
+    };
+    let text = match (&record.answer, &record.failure) {
+        (_, Some(_)) => status.as_str().to_string(),
+        (Some(_), None) if mask => MASK.to_string(),
+        (Some(answer), None) => answer.clone(),
+        (None, None) => "not implemented".to_string(),
+    };
+    let tooltip = match (&record.failure, &record.expected) {
+        (Some(failure), _) => failure.to_string(),
+        (None, Some(expected)) if status == Status::Fail && !mask => format!("expected {}", expected),
+        _ => status.as_str().to_string(),
+    };
+    format!(
+        "<span class=\"{}\" title=\"{}\">{}</span> <span class=\"answer\">{}</span>",
+        status.as_str(),
+        xml_escape(&tooltip),
+        mark,
+        xml_escape(&text)
+    )
+}
+
+/// A horizontal bar chart of the median runtimes, on a log scale with a
+/// grid line per power of ten.
+fn chart(parts: &[PartReport]) -> String {
+    const LABEL: f64 = 110.0;
+    const WIDTH: f64 = 720.0;
+    const ROW: f64 = 20.0;
+
+    let timed: Vec<(&PartReport, Duration)> = parts.iter().filter_map(|p| Some((p, p.median?))).collect();
+    if timed.is_empty() {
+        return "<p class=\"none\">No part was benchmarked.</p>\n".to_string();
+    }
+    let slowest = timed.iter().map(|&(_, d)| d).max().unwrap_or_default();
+    let decades = (slowest.as_secs_f64() / AXIS_START.as_secs_f64()).log10().ceil().max(1.0);
+    // Leave room after the longest bar for its label.
+    let scale = (WIDTH - LABEL - 70.0) / decades;
+    let x = |d: Duration| LABEL + scale * (d.as_secs_f64() / AXIS_START.as_secs_f64()).log10().max(0.0);
+    let height = ROW * timed.len() as f64 + 24.0;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" role=\"img\">",
+        w = WIDTH,
+        h = height
+    );
+    let units = ["1µs", "10µs", "100µs", "1ms", "10ms", "100ms", "1s", "10s", "100s"];
+    for decade in 0..=decades as usize {
+        let tick = LABEL + scale * decade as f64;
+        let label = units.get(decade).copied().unwrap_or("");
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{x:.1}\" y1=\"0\" x2=\"{x:.1}\" y2=\"{y:.1}\" stroke=\"#d1d9e0\"/><text x=\"{x:.1}\" y=\"{t:.1}\" text-anchor=\"middle\">{label}</text>",
+            x = tick,
+            y = height - 20.0,
+            t = height - 6.0,
+            label = label
+        );
+    }
+    for (row, (p, median)) in timed.iter().enumerate() {
+        let y = ROW * row as f64;
+        let end = x(*median).max(LABEL + 2.0);
+        let color = match p.real.status() {
+            Status::Pass => "#2da44e",
+            Status::Unchecked => "#d4a72c",
+            _ => "#cf222e",
+        };
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{t:.1}\">Day {} part {}</text><rect x=\"{l}\" y=\"{y:.1}\" width=\"{w:.1}\" height=\"{h}\" fill=\"{c}\"><title>{d:.2?}</title></rect><text x=\"{e:.1}\" y=\"{t:.1}\">{d:.2?}</text>",
+            p.day,
+            p.part,
+            t = y + 14.0,
+            l = LABEL,
+            y = y + 3.0,
+            w = end - LABEL,
+            h = ROW - 6.0,
+            c = color,
+            d = median,
+            e = end + 4.0
+        );
+    }
+    let _ = writeln!(svg, "</svg>");
+    svg
+}
+
+/// Render a part's doc comment: paragraphs, `- ` lists, `code` and
+/// **bold**, which is all the Markdown the solutions use.
+fn doc_html(doc: &str) -> String {
+    let mut html = String::new();
+    for block in doc.split("\n\n").map(str::trim).filter(|b| !b.is_empty()) {
+        if block.starts_with("- ") {
+            // Lines not starting a new item continue the previous one.
+            let mut items: Vec<String> = Vec::new();
+            for line in block.lines() {
+                match (line.strip_prefix("- "), items.last_mut()) {
+                    (Some(item), _) => items.push(item.trim().to_string()),
+                    (None, Some(last)) => {
+                        last.push(' ');
+                        last.push_str(line.trim());
+                    }
+                    (None, None) => items.push(line.trim().to_string()),
+                }
+            }
+            let _ = writeln!(html, "<ul>");
+            for item in items {
+                let _ = writeln!(html, "<li>{}</li>", inline_html(&item));
+            }
+            let _ = writeln!(html, "</ul>");
+        } else {
+            let text: Vec<&str> = block.lines().map(str::trim).collect();
+            let _ = writeln!(html, "<p>{}</p>", inline_html(&text.join(" ")));
+        }
+    }
+    html
+}
+
+/// Escape `text` and turn `code` spans and **bold** runs into HTML.
+fn inline_html(text: &str) -> String {
+    let mut html = String::new();
+    for (i, segment) in text.split('`').enumerate() {
+        if i % 2 == 1 {
+            let _ = write!(html, "<code>{}</code>", xml_escape(segment));
+            continue;
+        }
+        for (j, run) in segment.split("**").enumerate() {
+            let _ = match j % 2 {
+                1 => write!(html, "<strong>{}</strong>", xml_escape(run)),
+                _ => write!(html, "{}", xml_escape(run)),
+            };
+        }
+    }
+    html
+}
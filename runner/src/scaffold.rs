@@ -0,0 +1,189 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::answers::{self, Answers};
+
+/// Directory that `solvers/build.rs` scans for the default year's `dayNN.rs`
+/// files, relative to the repository root.
+pub const DAYS_DIR: &str = "solvers/src/days";
+
+/// The directory holding `year`'s solution modules, relative to the
+/// repository root: `solvers/src/days` for the default year, otherwise
+/// `solvers/src/yYYYY`.
+pub fn days_dir(year: u32) -> PathBuf {
+    if year == solvers::DEFAULT_YEAR {
+        PathBuf::from(DAYS_DIR)
+    } else {
+        PathBuf::from(format!("solvers/src/y{}", year))
+    }
+}
+
+/// Line separating the example text from its expected answer on stdin.
+const ANSWER_SEPARATOR: &str = "---";
+
+const TEMPLATE: &str = r#"//! Day DAY_NUM
+
+use crate::input::Input;
+
+pub(crate) fn parse_input(input: &Input) -> Vec<String> {
+    input.text()
+        .lines()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+pub fn part1(input: &Input) -> String {
+    let lines = parse_input(input);
+    todo!("day DAY_NUM part 1 ({} lines)", lines.len())
+}
+
+pub fn part2(input: &Input) -> String {
+    let lines = parse_input(input);
+    todo!("day DAY_NUM part 2 ({} lines)", lines.len())
+}
+"#;
+
+/// Scaffold a new day: the solver module plus empty real and example inputs.
+///
+/// With `from_stdin`, the example input is read from stdin. If it ends with a
+/// `---` line followed by an answer, that answer is registered as the
+/// expected part 1 example answer in `answers_path`.
+///
+/// Refuses to touch anything if one of the files already exists.
+pub fn run(year: u32, day: u32, from_stdin: bool, answers_path: &Path, root: &Path) -> Result<(), String> {
+    scaffold(year, day, from_stdin.then(io::stdin), answers_path, root)
+}
+
+/// [`run`], reading the example input from `stdin` if given.
+fn scaffold(year: u32, day: u32, stdin: Option<impl Read>, answers_path: &Path, root: &Path) -> Result<(), String> {
+    let module = root.join(days_dir(year)).join(format!("day{day:02}.rs"));
+    let real = solvers::input::conventional_path(year, day, false);
+    let example = solvers::input::conventional_path(year, day, true);
+
+    let existing: Vec<&PathBuf> = [&module, &real, &example].into_iter().filter(|p| p.exists()).collect();
+    if !existing.is_empty() {
+        let names: Vec<String> = existing.iter().map(|p| p.display().to_string()).collect();
+        return Err(format!("Refusing to overwrite existing files: {}", names.join(", ")));
+    }
+
+    let (example_text, expected) = match stdin {
+        Some(mut reader) => {
+            let mut text = String::new();
+            reader
+                .read_to_string(&mut text)
+                .map_err(|e| format!("Failed to read <stdin>: {}", e))?;
+            split_expected(&text)
+        }
+        None => (String::new(), None),
+    };
+
+    if expected.is_some() {
+        let answers = Answers::load(answers_path, year)?;
+        if answers.get(day, 1, true).is_some() {
+            return Err(format!(
+                "{} already has an example answer for day {} part 1",
+                answers_path.display(),
+                day
+            ));
+        }
+    }
+
+    let source = TEMPLATE.replace("DAY_NUM", &day.to_string());
+    create_new(&module, &source)?;
+    create_new(&real, "")?;
+    create_new(&example, &example_text)?;
+
+    if let Some(answer) = &expected {
+        answers::append(answers_path, year, day, 1, true, answer)?;
+        println!("Registered example answer {} for day {} part 1", answer, day);
+    }
+
+    println!("Created {}", module.display());
+    println!("Created {}", real.display());
+    println!("Created {}", example.display());
+
+    Ok(())
+}
+
+/// Split stdin into the example text and an optional expected answer after
+/// a `---` separator line.
+fn split_expected(text: &str) -> (String, Option<String>) {
+    let mut example = Vec::new();
+    let mut lines = text.lines();
+    for line in lines.by_ref() {
+        if line.trim() == ANSWER_SEPARATOR {
+            let answer = lines.map(str::trim).find(|l| !l.is_empty()).map(str::to_string);
+            return (join_lines(&example), answer);
+        }
+        example.push(line);
+    }
+    (join_lines(&example), None)
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    if lines.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", lines.join("\n"))
+    }
+}
+
+fn create_new(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(contents.as_bytes()))
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{inputs_dir, scratch_dir};
+
+    #[test]
+    fn expected_answers_follow_the_separator() {
+        assert_eq!(split_expected("1\n2\n---\n\n 42 \n"), ("1\n2\n".to_string(), Some("42".to_string())));
+        assert_eq!(split_expected("1\n2\n"), ("1\n2\n".to_string(), None));
+        assert_eq!(split_expected("1\n ---\n"), ("1\n".to_string(), None));
+        assert_eq!(split_expected(""), (String::new(), None));
+    }
+
+    #[test]
+    fn scaffolds_a_day_and_registers_its_example_answer() {
+        inputs_dir();
+        let root = scratch_dir("scaffold-new");
+        let answers_path = root.join("answers.toml");
+        let year = solvers::DEFAULT_YEAR;
+        scaffold(year, 20, Some("1\n2\n---\n3\n".as_bytes()), &answers_path, &root).unwrap();
+
+        let module = fs::read_to_string(root.join(DAYS_DIR).join("day20.rs")).unwrap();
+        assert!(module.starts_with("//! Day 20\n"));
+        let example = solvers::input::conventional_path(year, 20, true);
+        assert_eq!(fs::read_to_string(example).unwrap(), "1\n2\n");
+        assert_eq!(fs::read_to_string(solvers::input::conventional_path(year, 20, false)).unwrap(), "");
+        let answers = Answers::load(&answers_path, year).unwrap();
+        assert_eq!(answers.get(20, 1, true), Some("3"));
+    }
+
+    #[test]
+    fn existing_days_are_not_overwritten() {
+        inputs_dir();
+        let root = scratch_dir("scaffold-existing");
+        let module = root.join(DAYS_DIR).join("day21.rs");
+        create_new(&module, "// mine\n").unwrap();
+
+        let e = scaffold(solvers::DEFAULT_YEAR, 21, None::<&[u8]>, &root.join("answers.toml"), &root).unwrap_err();
+        assert!(e.starts_with("Refusing to overwrite existing files: "), "{}", e);
+        assert_eq!(fs::read_to_string(&module).unwrap(), "// mine\n");
+        assert!(!solvers::input::conventional_path(solvers::DEFAULT_YEAR, 21, false).exists());
+    }
+}
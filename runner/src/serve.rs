@@ -0,0 +1,267 @@
+use std::{net::SocketAddr, thread};
+
+use serde::Serialize;
+use serde_json::json;
+use solvers::Source;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    answers::Answers,
+    isolate::Isolation,
+    output::JsonRecord,
+    params,
+    record::{Record, Status},
+};
+
+/// What every request runs against.
+struct Context<'a> {
+    year: u32,
+    answers: &'a Answers,
+    params: &'a params::Configured,
+    isolation: &'a Isolation,
+}
+
+#[derive(Serialize)]
+struct DayInfo {
+    day: u32,
+    parts: [u8; 2],
+}
+
+/// Serve `year`'s solvers over a small JSON HTTP API on `127.0.0.1:port`,
+/// using `workers` threads to handle requests.
+///
+/// - `GET /days` lists the implemented days.
+/// - `GET /run/<day>/<part>/<example|real>` runs a part on its conventional input.
+/// - `POST /run/<day>/<part>/<example|real>` runs a part on the request body.
+///
+/// Run responses have the same fields as `--format json`.
+pub fn run(
+    year: u32,
+    port: u16,
+    answers: &Answers,
+    params: &params::Configured,
+    isolation: &Isolation,
+    workers: usize,
+) -> Result<(), String> {
+    let api = Api::bind(port)?;
+    println!("Listening on http://{}", api.addr());
+    api.serve(year, answers, params, isolation, workers);
+    Ok(())
+}
+
+/// A bound API server that is not yet handling requests.
+pub struct Api {
+    server: Server,
+}
+
+impl Api {
+    /// Bind `127.0.0.1:port`; port 0 picks a free one.
+    pub fn bind(port: u16) -> Result<Self, String> {
+        let server = Server::http(("127.0.0.1", port)).map_err(|e| format!("Failed to bind 127.0.0.1:{}: {}", port, e))?;
+        Ok(Api { server })
+    }
+
+    /// The address the server is bound to.
+    pub fn addr(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("servers bound by `bind` listen on an IP address")
+    }
+
+    /// Handle requests on `workers` threads, each until the server is
+    /// unblocked for it.
+    pub fn serve(
+        &self,
+        year: u32,
+        answers: &Answers,
+        params: &params::Configured,
+        isolation: &Isolation,
+        workers: usize,
+    ) {
+        let context = Context {
+            year,
+            answers,
+            params,
+            isolation,
+        };
+        thread::scope(|scope| {
+            for _ in 0..workers.max(1) {
+                scope.spawn(|| {
+                    for request in self.server.incoming_requests() {
+                        handle(request, &context);
+                    }
+                });
+            }
+        });
+    }
+}
+
+fn handle(mut request: Request, context: &Context) {
+    let method = request.method().clone();
+    let path = request.url().split('?').next().unwrap_or("").to_string();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let (status, body) = match (&method, segments.as_slice()) {
+        (Method::Get, ["days"]) => {
+            let days: Vec<DayInfo> = solvers::implemented_days(context.year)
+                .iter()
+                .map(|&day| DayInfo { day, parts: [1, 2] })
+                .collect();
+            (200, json!({ "days": days }))
+        }
+        (Method::Get | Method::Post, ["run", day, part, mode]) => {
+            let body = if method == Method::Post {
+                let mut text = String::new();
+                match request.as_reader().read_to_string(&mut text) {
+                    Ok(_) => Some(text),
+                    Err(e) => {
+                        respond(request, 400, json!({ "error": format!("Failed to read body: {}", e) }));
+                        return;
+                    }
+                }
+            } else {
+                None
+            };
+            run_part(context, day, part, mode, body)
+        }
+        _ => (404, json!({ "error": format!("No route for {} {}", method, path) })),
+    };
+
+    respond(request, status, body);
+}
+
+/// Run one day/part for the `/run` endpoints; `body` replaces the
+/// conventional input when given.
+fn run_part(context: &Context, day: &str, part: &str, mode: &str, body: Option<String>) -> (u16, serde_json::Value) {
+    let Ok(day) = day.parse::<u32>() else {
+        return (400, json!({ "error": format!("Invalid day '{}'", day) }));
+    };
+    let part = match part.parse::<u8>() {
+        Ok(p @ 1..=2) => p,
+        _ => return (400, json!({ "error": format!("Invalid part '{}'", part) })),
+    };
+    let use_example = match mode {
+        "example" => true,
+        "real" => false,
+        _ => return (400, json!({ "error": format!("Invalid mode '{}', expected example or real", mode) })),
+    };
+
+    let input = context.params.input(context.year, day, use_example);
+    let (input, expected) = match body {
+        Some(text) => (input.with_source(Source::Text(text)), None),
+        // Registered answers only apply to the checked-in inputs.
+        None => (input, context.answers.get(day, part, use_example).map(str::to_string)),
+    };
+
+    let record = Record::run(part, &input, expected, context.isolation);
+    let status = match record.status() {
+        Status::Skipped => 404,
+        _ => 200,
+    };
+    let mut json = serde_json::to_value(JsonRecord::from(&record)).expect("records serialize to JSON");
+    if status == 404 {
+        json["error"] = json!(format!("Day {} is not implemented yet", day));
+    }
+    (status, json)
+}
+
+fn respond(request: Request, status: u16, body: serde_json::Value) {
+    let header = Header::from_bytes("Content-Type", "application/json").expect("static header is valid");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    // The client may have disconnected; nothing useful to do about it.
+    let _ = request.respond(response);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::Value;
+    use ureq::Agent;
+
+    use super::*;
+    use crate::test_support::{inputs_dir, scratch_dir};
+
+    /// Serve on a free port for the duration of `requests`, which gets the
+    /// server's base URL and an agent to make requests with.
+    fn with_api(name: &str, requests: impl FnOnce(&Agent, &str)) {
+        inputs_dir();
+        let isolation = Isolation {
+            timeout: Some(Duration::from_secs(30)),
+            day_timeouts: Default::default(),
+            repro_dir: scratch_dir(name).join("repro"),
+            track_memory: false,
+            cache: None,
+        };
+        let (answers, params) = (Answers::default(), params::Configured::default());
+        let api = Api::bind(0).unwrap();
+        let url = format!("http://{}", api.addr());
+        let agent: Agent = Agent::config_builder().http_status_as_error(false).build().into();
+
+        thread::scope(|scope| {
+            scope.spawn(|| api.serve(solvers::DEFAULT_YEAR, &answers, &params, &isolation, 1));
+            requests(&agent, &url);
+            api.server.unblock();
+        });
+    }
+
+    /// The status and JSON body of a response.
+    fn read(response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> (u16, Value) {
+        let mut response = response.unwrap();
+        let body = response.body_mut().read_to_string().unwrap();
+        (response.status().as_u16(), serde_json::from_str(&body).unwrap())
+    }
+
+    #[test]
+    fn lists_the_implemented_days() {
+        with_api("serve-days", |agent, url| {
+            let (status, body) = read(agent.get(format!("{}/days", url)).call());
+            assert_eq!(status, 200);
+            let days = body["days"].as_array().unwrap();
+            assert_eq!(days.len(), solvers::implemented_days(solvers::DEFAULT_YEAR).len());
+            assert_eq!(days[0], json!({ "day": 1, "parts": [1, 2] }));
+        });
+    }
+
+    #[test]
+    fn runs_a_part_on_its_conventional_input() {
+        with_api("serve-get", |agent, url| {
+            let (status, body) = read(agent.get(format!("{}/run/1/1/real", url)).call());
+            assert_eq!(status, 200);
+            // The stand-in inputs hold the example.
+            assert_eq!(body["answer"], "3");
+            assert_eq!(body["mode"], "real");
+        });
+    }
+
+    #[test]
+    fn runs_a_part_on_the_request_body() {
+        with_api("serve-post", |agent, url| {
+            let (status, body) = read(agent.post(format!("{}/run/1/1/example", url)).send("R50\nL5\nR5\n"));
+            assert_eq!(status, 200);
+            assert_eq!(body["answer"], "2");
+            assert_eq!(body["mode"], "example");
+        });
+    }
+
+    #[test]
+    fn unimplemented_days_are_not_found() {
+        with_api("serve-missing", |agent, url| {
+            let (status, body) = read(agent.get(format!("{}/run/25/1/real", url)).call());
+            assert_eq!(status, 404);
+            assert_eq!(body["error"], "Day 25 is not implemented yet");
+        });
+    }
+
+    #[test]
+    fn bad_parts_are_rejected() {
+        with_api("serve-part", |agent, url| {
+            let (status, body) = read(agent.get(format!("{}/run/1/3/real", url)).call());
+            assert_eq!(status, 400);
+            assert_eq!(body["error"], "Invalid part '3'");
+        });
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use crate::{
+    client::Client,
+    guesses::{self, GuessLog, Verdict},
+    isolate::Isolation,
+    params,
+    record::Record,
+};
+
+/// Solve a day/part on the real input and submit the answer.
+///
+/// The answer is refused without contacting the site if the guess log at
+/// `log_path` already rules it out. Every submission is appended to the log.
+/// Unless `yes` is set, asks for confirmation first.
+///
+/// Returns `Ok(true)` if the site accepted the answer.
+pub fn run(
+    day: u32,
+    part: u8,
+    client: &mut Client,
+    log_path: &Path,
+    params: &params::Configured,
+    isolation: &Isolation,
+    yes: bool,
+) -> Result<bool, String> {
+    let record = Record::run(part, &params.input(client.year(), day, false), None, isolation);
+    if let Some(failure) = &record.failure {
+        return Err(format!("Day {} part {} {}", day, part, failure));
+    }
+    let Some(answer) = record.answer else {
+        return Err(format!("Day {} is not implemented yet", day));
+    };
+
+    let log = GuessLog::load(log_path, client.year())?;
+    if let Some(reason) = log.reject_reason(day, part, &answer) {
+        return Err(format!("Refusing to submit {}: {}", answer, reason));
+    }
+
+    let (low, high) = log.bounds(day, part);
+    let previous = log.for_part(day, part).count();
+    println!(
+        "Day {} part {}: answer {} ({} previous guess(es); known bounds: {} < answer < {})",
+        day,
+        part,
+        answer,
+        previous,
+        low.map_or("-".to_string(), |v| v.to_string()),
+        high.map_or("-".to_string(), |v| v.to_string())
+    );
+
+    if !yes && !confirm("Submit it? [y/N] ")? {
+        println!("Not submitted.");
+        return Ok(false);
+    }
+
+    let body = client.submit(day, part, &answer)?;
+    let verdict = Verdict::from_response(&body);
+    guesses::append(log_path, client.year(), day, part, &answer, verdict)?;
+
+    match verdict {
+        Verdict::Correct => println!("⭐ Correct! Consider adding {} to the answer registry.", answer),
+        Verdict::TooHigh => println!("❌ {} is too high", answer),
+        Verdict::TooLow => println!("❌ {} is too low", answer),
+        Verdict::Wrong => println!("❌ {} is not the right answer", answer),
+        Verdict::RateLimited => println!("⏳ Submitted too recently; wait before trying again"),
+        Verdict::WrongLevel => println!("🤔 Part {} is already solved or not unlocked yet", part),
+        Verdict::Unknown => println!("🤔 Could not understand the response:\n{}", body.trim()),
+    }
+
+    Ok(verdict == Verdict::Correct)
+}
+
+fn confirm(prompt: &str) -> Result<bool, String> {
+    print!("{}", prompt);
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| format!("Failed to read <stdin>: {}", e))?;
+    Ok(matches!(line.trim(), "y" | "Y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, time::Duration};
+
+    use super::*;
+    use crate::test_support::{inputs_dir, scratch_dir, serve};
+
+    /// Day 1 part 1 on the stand-in inputs, which hold its example.
+    const ANSWER: &str = "3";
+
+    fn isolation(dir: &Path) -> Isolation {
+        Isolation {
+            timeout: Some(Duration::from_secs(30)),
+            day_timeouts: Default::default(),
+            repro_dir: dir.join("repro"),
+            track_memory: false,
+            cache: None,
+        }
+    }
+
+    /// Submit day 1 part 1 to a stand-in answering with `responses`, with
+    /// `log` already in the guess log. Returns the result, the number of
+    /// requests the stand-in got and the guess log's path.
+    fn submit(
+        name: &str,
+        responses: Vec<(u16, &'static str)>,
+        log: &[(&str, Verdict)],
+    ) -> (Result<bool, String>, usize, PathBuf) {
+        inputs_dir();
+        let dir = scratch_dir(name);
+        let log_path = dir.join("guesses.toml");
+        for &(answer, verdict) in log {
+            guesses::append(&log_path, solvers::DEFAULT_YEAR, 1, 1, answer, verdict).unwrap();
+        }
+        let (url, server) = serve(responses);
+        let mut client = Client::new(&url, "secret".to_string(), solvers::DEFAULT_YEAR, Duration::ZERO);
+        let result = run(1, 1, &mut client, &log_path, &params::Configured::default(), &isolation(&dir), true);
+        (result, server.join().unwrap().len(), log_path)
+    }
+
+    #[test]
+    fn correct_answers_are_logged() {
+        let (result, received, log_path) = submit("correct", vec![(200, "That's the right answer!")], &[]);
+        assert_eq!(result, Ok(true));
+        assert_eq!(received, 1);
+        let log = GuessLog::load(&log_path, solvers::DEFAULT_YEAR).unwrap();
+        let guesses: Vec<_> = log.for_part(1, 1).map(|g| (g.answer.as_str(), g.verdict)).collect();
+        assert_eq!(guesses, [(ANSWER, Verdict::Correct)]);
+    }
+
+    #[test]
+    fn rate_limited_submissions_are_logged_but_fail() {
+        let body = "You gave an answer too recently; you have 30s left to wait.";
+        let (result, received, log_path) = submit("rate-limited", vec![(200, body)], &[]);
+        assert_eq!(received, 1);
+        assert_eq!(result, Ok(false));
+        let log = GuessLog::load(&log_path, solvers::DEFAULT_YEAR).unwrap();
+        assert_eq!(log.for_part(1, 1).last().map(|g| g.verdict), Some(Verdict::RateLimited));
+    }
+
+    #[test]
+    fn known_wrong_answers_are_refused_without_a_request() {
+        let (result, received, _) = submit("known-wrong", Vec::new(), &[(ANSWER, Verdict::TooLow)]);
+        let error = result.unwrap_err();
+        assert!(error.starts_with("Refusing to submit 3"), "{}", error);
+        assert_eq!(received, 0);
+    }
+
+    #[test]
+    fn server_errors_are_not_logged() {
+        let (result, _, log_path) = submit("server-error", vec![(502, "Bad Gateway")], &[]);
+        assert!(result.unwrap_err().contains("returned 502"));
+        assert!(fs::metadata(&log_path).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+    thread::{self, JoinHandle},
+};
+
+use tiny_http::{Response, Server};
+
+/// One request the stand-in received.
+#[derive(Debug)]
+pub struct Received {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for the Advent of Code site: serve `responses` (status
+/// and body), one per request, on a free local port. Returns the base URL
+/// and a handle yielding what was received.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Received>>) {
+    let server = Server::http("127.0.0.1:0").expect("failed to bind a local port");
+    let url = format!("http://{}", server.server_addr().to_ip().expect("an IP address"));
+    let handle = thread::spawn(move || {
+        let mut received = Vec::new();
+        for (status, body) in responses {
+            let mut request = server.recv().expect("failed to receive a request");
+            let mut text = String::new();
+            request.as_reader().read_to_string(&mut text).expect("failed to read a request body");
+            received.push(Received {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie: request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string()),
+                body: text,
+            });
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .expect("failed to respond");
+        }
+        received
+    });
+    (url, handle)
+}
+
+/// A scratch directory for one test, emptied first.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("runner-test-{}-{}", process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("failed to create a scratch directory");
+    dir
+}
+
+/// Conventional inputs for tests that solve or fetch: a scratch
+/// directory holding the day 1 example as the real day 1 input. The
+/// inputs directory is process-wide, so every test shares this one.
+pub fn inputs_dir() -> &'static Path {
+    static DIR: OnceLock<PathBuf> = OnceLock::new();
+    DIR.get_or_init(|| {
+        let dir = scratch_dir("inputs");
+        fs::write(dir.join("day01.txt"), "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n")
+            .expect("failed to write an input");
+        solvers::input::set_inputs_dir(dir.clone());
+        dir
+    })
+}
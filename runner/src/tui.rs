@@ -0,0 +1,380 @@
+use std::{
+    collections::HashMap,
+    io,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+    DefaultTerminal, Frame,
+};
+use crate::{
+    answers::Answers,
+    isolate::{self, Failure, Isolation},
+    mode_name, params, LAST_DAY,
+};
+
+/// Runs slower than this are flagged as slow.
+const SLOW: Duration = Duration::from_secs(1);
+
+/// Days per row in the calendar grid.
+const COLUMNS: u32 = 5;
+
+/// How often the screen is redrawn while waiting for keys, so that results
+/// from the worker show up as they arrive.
+const TICK: Duration = Duration::from_millis(50);
+
+/// `(day, part, use_example)`
+type Key = (u32, u8, bool);
+
+/// A batch of runs for the worker thread, and the message to show once all
+/// of them are done.
+struct Job {
+    runs: Vec<Key>,
+    done: String,
+}
+
+/// What the worker thread sends back.
+enum Update {
+    Ran(Key, RunResult),
+    Done(String),
+}
+
+/// Outcome of the latest run of one day/part/input.
+struct RunResult {
+    answer: Result<String, Failure>,
+    elapsed: Duration,
+}
+
+/// What the grid shows for one day/part.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PartState {
+    NotImplemented,
+    /// Implemented but not run (or no registered answer to compare against).
+    Implemented,
+    Verified,
+    Failing,
+    Slow,
+}
+
+impl PartState {
+    fn symbol(self) -> (&'static str, Color) {
+        match self {
+            PartState::NotImplemented => (" ", Color::DarkGray),
+            PartState::Implemented => ("·", Color::White),
+            PartState::Verified => ("★", Color::Yellow),
+            PartState::Failing => ("✗", Color::Red),
+            PartState::Slow => ("◷", Color::Magenta),
+        }
+    }
+}
+
+struct App<'a> {
+    year: u32,
+    answers: &'a Answers,
+    day: u32,
+    part: u8,
+    /// `(day, part, use_example) -> latest result`
+    results: HashMap<Key, RunResult>,
+    message: String,
+    jobs: Sender<Job>,
+    /// Jobs sent to the worker that have not finished yet.
+    pending: usize,
+}
+
+impl App<'_> {
+    /// Queue `runs` on the worker thread.
+    fn queue(&mut self, runs: Vec<Key>, running: String, done: String) {
+        // The worker only stops once this sender is dropped.
+        let _ = self.jobs.send(Job { runs, done });
+        self.pending += 1;
+        self.message = if self.pending > 1 {
+            format!("{} (queued behind {} more)", running, self.pending - 1)
+        } else {
+            running
+        };
+    }
+
+    fn update(&mut self, update: Update) {
+        match update {
+            Update::Ran(key, result) => {
+                self.results.insert(key, result);
+            }
+            Update::Done(message) => {
+                self.pending -= 1;
+                if self.pending == 0 {
+                    self.message = message;
+                }
+            }
+        }
+    }
+
+    fn state(&self, day: u32, part: u8) -> PartState {
+        if !solvers::implemented_days(self.year).contains(&day) {
+            return PartState::NotImplemented;
+        }
+
+        let mut state = PartState::Implemented;
+        for use_example in [true, false] {
+            let Some(result) = self.results.get(&(day, part, use_example)) else {
+                continue;
+            };
+            let expected = self.answers.get(day, part, use_example);
+            let failed = match &result.answer {
+                Err(_) => true,
+                Ok(answer) => expected.is_some_and(|exp| exp != answer),
+            };
+            if failed {
+                return PartState::Failing;
+            }
+            if result.elapsed > SLOW {
+                state = PartState::Slow;
+            } else if expected.is_some() && state == PartState::Implemented {
+                state = PartState::Verified;
+            }
+        }
+        state
+    }
+
+    fn select(&mut self, delta: i64) {
+        self.day = (self.day as i64 + delta).clamp(1, LAST_DAY as i64) as u32;
+    }
+}
+
+/// Run the solvers of each job in turn, off the UI thread. Returns once the
+/// UI has gone away.
+fn worker(
+    year: u32,
+    params: params::Configured,
+    isolation: Isolation,
+    jobs: Receiver<Job>,
+    updates: Sender<Update>,
+) {
+    for job in jobs {
+        for (day, part, use_example) in job.runs {
+            let input = params.input(year, day, use_example);
+            let start = Instant::now();
+            let answer = match isolate::run(part, &input, &isolation) {
+                Ok(Some(solved)) => Ok(solved.answer),
+                Ok(None) => continue,
+                Err(failure) => Err(failure),
+            };
+            let result = RunResult {
+                answer,
+                elapsed: start.elapsed(),
+            };
+            if updates.send(Update::Ran((day, part, use_example), result)).is_err() {
+                return;
+            }
+        }
+        if updates.send(Update::Done(job.done)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Interactive terminal dashboard: a calendar grid showing which parts are
+/// implemented, verified, failing or slow, with keys to run the selected
+/// day/part on either input.
+///
+/// Solvers run on a worker thread, so the dashboard stays responsive (and
+/// can be quit) while they do.
+pub fn run(year: u32, answers: &Answers, params: &params::Configured, isolation: &Isolation) -> io::Result<()> {
+    let (jobs, job_receiver) = mpsc::channel();
+    let (update_sender, updates) = mpsc::channel();
+    let (params, isolation) = (params.clone(), isolation.clone());
+    // Detached: a solver still running on quit is abandoned with the process.
+    thread::spawn(move || worker(year, params, isolation, job_receiver, update_sender));
+
+    let mut app = App {
+        year,
+        answers,
+        day: 1,
+        part: 1,
+        results: HashMap::new(),
+        message: "Press `a` to run every day on both inputs".to_string(),
+        jobs,
+        pending: 0,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app, &updates);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App, updates: &Receiver<Update>) -> io::Result<()> {
+    loop {
+        while let Ok(update) = updates.try_recv() {
+            app.update(update);
+        }
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if !event::poll(TICK)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Left | KeyCode::Char('h') => app.select(-1),
+            KeyCode::Right | KeyCode::Char('l') => app.select(1),
+            KeyCode::Up | KeyCode::Char('k') => app.select(-(COLUMNS as i64)),
+            KeyCode::Down | KeyCode::Char('j') => app.select(COLUMNS as i64),
+            KeyCode::Tab | KeyCode::Char('p') => app.part = 3 - app.part,
+            KeyCode::Char('1') => app.part = 1,
+            KeyCode::Char('2') => app.part = 2,
+            KeyCode::Char(c @ ('e' | 'r')) => {
+                let use_example = c == 'e';
+                let (day, part) = (app.day, app.part);
+                app.queue(
+                    vec![(day, part, use_example)],
+                    format!("Running day {} part {} ({})…", day, part, mode_name(use_example)),
+                    format!("Ran day {} part {} ({})", day, part, mode_name(use_example)),
+                );
+            }
+            KeyCode::Char('a') => {
+                let mut runs = Vec::new();
+                for &day in solvers::implemented_days(app.year) {
+                    for part in 1..=2u8 {
+                        for use_example in [true, false] {
+                            runs.push((day, part, use_example));
+                        }
+                    }
+                }
+                app.queue(
+                    runs,
+                    "Running every day on both inputs…".to_string(),
+                    "Ran every day on both inputs".to_string(),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, app: &App) {
+    let [main, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [grid, side] = Layout::horizontal([Constraint::Length(COLUMNS as u16 * 12 + 2), Constraint::Min(30)]).areas(main);
+
+    draw_grid(frame, app, grid);
+    draw_side(frame, app, side);
+
+    frame.render_widget(
+        Paragraph::new(format!(
+            " ←↓↑→/hjkl move · tab part · e example · r real · a all · q quit │ {}",
+            app.message
+        ))
+        .style(Style::default().fg(Color::DarkGray)),
+        help,
+    );
+}
+
+fn draw_grid(frame: &mut Frame, app: &App, area: Rect) {
+    let mut lines = vec![Line::default()];
+    for row in 0..LAST_DAY.div_ceil(COLUMNS) {
+        let mut spans = Vec::new();
+        for col in 0..COLUMNS {
+            let day = row * COLUMNS + col + 1;
+            if day > LAST_DAY {
+                break;
+            }
+            let selected = day == app.day;
+            let implemented = solvers::implemented_days(app.year).contains(&day);
+
+            let mut label = Style::default().fg(if implemented { Color::White } else { Color::DarkGray });
+            if selected {
+                label = label.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(format!("{:>2}", day), label));
+            spans.push(Span::raw(" "));
+            for part in 1..=2u8 {
+                let (symbol, color) = app.state(day, part).symbol();
+                let mut style = Style::default().fg(color);
+                if selected && part == app.part {
+                    style = style.add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
+                }
+                spans.push(Span::styled(symbol, style));
+                spans.push(Span::raw(" "));
+            }
+            spans.push(Span::raw("  "));
+        }
+        lines.push(Line::from(spans));
+        lines.push(Line::default());
+    }
+    lines.push(Line::from(vec![
+        Span::styled(" ★", Style::default().fg(Color::Yellow)),
+        Span::raw(" verified  "),
+        Span::styled("✗", Style::default().fg(Color::Red)),
+        Span::raw(" failing  "),
+        Span::styled("◷", Style::default().fg(Color::Magenta)),
+        Span::raw(" slow  "),
+        Span::raw("· not run"),
+    ]));
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(format!(" Advent of Code {} ", app.year))),
+        area,
+    );
+}
+
+fn draw_side(frame: &mut Frame, app: &App, area: Rect) {
+    let mut lines = Vec::new();
+    if !solvers::implemented_days(app.year).contains(&app.day) {
+        lines.push(Line::from("Not implemented yet"));
+    }
+
+    for use_example in [true, false] {
+        let key = (app.day, app.part, use_example);
+        let expected = app.answers.get(app.day, app.part, use_example);
+
+        lines.push(Line::styled(
+            mode_name(use_example).to_string(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        match app.results.get(&key) {
+            None => lines.push(Line::from("  answer:   (not run)")),
+            Some(result) => {
+                match &result.answer {
+                    Ok(answer) => {
+                        let (mark, color) = match expected {
+                            Some(exp) if exp == answer => ("✅", Color::Green),
+                            Some(_) => ("❌", Color::Red),
+                            None => ("", Color::White),
+                        };
+                        lines.push(Line::from(vec![
+                            Span::raw("  answer:   "),
+                            Span::styled(format!("{} {}", answer, mark), Style::default().fg(color)),
+                        ]));
+                    }
+                    Err(failure) => lines.push(Line::styled(
+                        format!("  {}", failure),
+                        Style::default().fg(Color::Red),
+                    )),
+                }
+                lines.push(Line::from(format!("  time:     {:.2?}", result.elapsed)));
+            }
+        }
+        lines.push(Line::from(format!("  expected: {}", expected.unwrap_or("-"))));
+        lines.push(Line::default());
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(format!(" Day {} part {} ", app.day, app.part))),
+        area,
+    );
+}
@@ -0,0 +1,85 @@
+use crate::scaffold;
+
+/// Which solution variants of a day to run.
+#[derive(Clone, Debug)]
+pub enum VariantSelector {
+    /// Only the day's main `dayNN.rs` module.
+    Main,
+    /// One named `dayNN_<name>.rs` variant.
+    Named(String),
+    /// The main module and every variant, for comparing them.
+    All,
+}
+
+impl VariantSelector {
+    /// `--variant <name>`, or every variant with `all`.
+    pub fn new(variant: Option<String>, all: bool) -> Self {
+        match (variant, all) {
+            (_, true) => VariantSelector::All,
+            (Some(name), false) => VariantSelector::Named(name),
+            (None, false) => VariantSelector::Main,
+        }
+    }
+
+    /// The variants to run for a day/part, `None` standing for the main
+    /// module. Variants that do not implement the part are left out.
+    pub fn select(&self, year: u32, day: u32, part: u8) -> Vec<Option<String>> {
+        let implemented = solvers::variants(year, day)
+            .iter()
+            .filter(|v| v.parts.contains(&part))
+            .map(|v| v.name);
+        match self {
+            VariantSelector::Main => vec![None],
+            VariantSelector::Named(name) => implemented.filter(|v| v == name).map(|v| Some(v.to_string())).collect(),
+            VariantSelector::All => std::iter::once(None)
+                .chain(implemented.map(|v| Some(v.to_string())))
+                .collect(),
+        }
+    }
+}
+
+/// Check that `day` has a variant called `name` implementing `part` (or
+/// either part if `None`), with a message listing the alternatives if not.
+pub fn check(year: u32, day: u32, part: Option<u8>, name: &str) -> Result<(), String> {
+    let variants = solvers::variants(year, day);
+    let Some(variant) = variants.iter().find(|v| v.name == name) else {
+        let names: Vec<&str> = variants.iter().map(|v| v.name).collect();
+        return Err(if names.is_empty() {
+            format!("Day {} has no variants", day)
+        } else {
+            format!("Day {} has no variant '{}'; available: {}", day, name, names.join(", "))
+        });
+    };
+    match part {
+        Some(part) if !variant.parts.contains(&part) => Err(format!(
+            "Variant '{}' of day {} does not implement part {}",
+            name, day, part
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// List the solutions of a day: its main module and every variant, with the
+/// parts each implements.
+pub fn run(year: u32, day: u32) -> Result<(), String> {
+    if !solvers::implemented_days(year).contains(&day) {
+        return Err(format!("Day {} is not implemented yet", day));
+    }
+
+    match solvers::title(year, day) {
+        Some(title) => println!("Day {}: {}", day, title),
+        None => println!("Day {}", day),
+    }
+    let dir = scaffold::days_dir(year);
+    println!("  {:<12}  {:<8}  {}", "default", "1, 2", dir.join(format!("day{:02}.rs", day)).display());
+    for variant in solvers::variants(year, day) {
+        let parts: Vec<String> = variant.parts.iter().map(u8::to_string).collect();
+        println!(
+            "  {:<12}  {:<8}  {}",
+            variant.name,
+            parts.join(", "),
+            dir.join(format!("day{:02}_{}.rs", day, variant.name)).display()
+        );
+    }
+    Ok(())
+}
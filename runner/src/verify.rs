@@ -0,0 +1,76 @@
+use crate::{
+    answers::Answers,
+    isolate::Isolation,
+    mode_name, params, pool,
+    record::{Record, Status},
+};
+
+/// Check every known answer against the solvers and report pass/fail/missing
+/// per entry. Implemented day/parts without a registered answer are reported
+/// as missing but do not count as failures.
+///
+/// Returns `true` when no registered answer mismatched, panicked or timed out.
+pub fn run(year: u32, answers: &Answers, params: &params::Configured, isolation: &Isolation, jobs: usize) -> bool {
+    let mut passed = 0usize;
+    let mut failed = 0usize;
+    let mut missing = 0usize;
+
+    let entries: Vec<(u32, u8, bool, &str)> = answers.iter().collect();
+    let records = pool::run_ordered(&entries, jobs, |&(day, part, use_example, expected)| {
+        Record::run(part, &params.input(year, day, use_example), Some(expected.to_string()), isolation)
+    });
+
+    for (&(day, part, use_example, expected), record) in entries.iter().zip(&records) {
+        let mode = mode_name(use_example);
+        let answer = record.answer.as_deref().unwrap_or_default();
+
+        match record.status() {
+            Status::Pass => {
+                passed += 1;
+                println!("✅ PASS     day {:>2} part {} {:<7}  {}", day, part, mode, answer);
+            }
+            Status::Fail => {
+                failed += 1;
+                println!(
+                    "❌ FAIL     day {:>2} part {} {:<7}  expected {}, got {}",
+                    day, part, mode, expected, answer
+                );
+            }
+            Status::Panic | Status::Timeout => {
+                failed += 1;
+                println!(
+                    "❌ FAIL     day {:>2} part {} {:<7}  expected {}, {}",
+                    day,
+                    part,
+                    mode,
+                    expected,
+                    record.failure.as_ref().map_or(String::new(), ToString::to_string)
+                );
+            }
+            Status::Unchecked => unreachable!("an expected answer is always given"),
+            Status::Skipped => {
+                failed += 1;
+                println!(
+                    "❌ FAIL     day {:>2} part {} {:<7}  expected {}, day is not implemented",
+                    day, part, mode, expected
+                );
+            }
+        }
+    }
+
+    for &day in solvers::implemented_days(year) {
+        for part in 1..=2u8 {
+            for use_example in [true, false] {
+                if answers.get(day, part, use_example).is_none() {
+                    missing += 1;
+                    println!("❔ MISSING  day {:>2} part {} {:<7}", day, part, mode_name(use_example));
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    failed == 0
+}
@@ -0,0 +1,125 @@
+use std::{
+    env, fs,
+    path::Path,
+    process::Command,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{answers::Answers, mode_name, scaffold};
+
+/// ANSI sequence to clear the screen and move the cursor to the top left.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Re-run a day/part whenever its source, its input or the answer registry
+/// changes, polling modification times every `interval`.
+///
+/// Each run goes through `cargo run`, so edits to the solver are rebuilt
+/// before the answer is computed. The runs skip the example gate, since the
+/// watched input is shown whatever the example gives, and are not logged to
+/// the history. Runs until interrupted.
+pub fn run(
+    year: u32,
+    day: u32,
+    part: u8,
+    use_example: bool,
+    answers_path: &Path,
+    root: &Path,
+    interval: Duration,
+) -> ! {
+    let watched = vec![
+        root.join(scaffold::days_dir(year)).join(format!("day{day:02}.rs")),
+        solvers::input::conventional_path(year, day, use_example),
+        answers_path.to_path_buf(),
+    ];
+
+    let mut last_seen: Option<Vec<Option<SystemTime>>> = None;
+    let mut previous: Option<String> = None;
+    let mut runs = 0u32;
+
+    loop {
+        let stamps: Vec<Option<SystemTime>> = watched.iter().map(|p| modified(p)).collect();
+        if last_seen.as_ref() != Some(&stamps) {
+            last_seen = Some(stamps);
+            runs += 1;
+
+            print!("{}", CLEAR_SCREEN);
+            println!(
+                "Watching day {} part {} ({}), run #{} — Ctrl-C to stop",
+                day,
+                part,
+                mode_name(use_example),
+                runs
+            );
+            for path in &watched {
+                println!("  {}", path.display());
+            }
+            println!();
+
+            let start = Instant::now();
+            let outcome = run_child(year, day, part, use_example, answers_path, root);
+            let elapsed = start.elapsed();
+
+            let expected = Answers::load(answers_path, year)
+                .ok()
+                .and_then(|a| a.get(day, part, use_example).map(str::to_string));
+
+            match outcome {
+                Ok(answer) => {
+                    println!("Answer:   {}", answer);
+                    println!("Previous: {}", previous.as_deref().unwrap_or("-"));
+                    match &expected {
+                        Some(exp) if *exp == answer => println!("Expected: {} ✅", exp),
+                        Some(exp) => println!("Expected: {} ❌", exp),
+                        None => println!("Expected: -"),
+                    }
+                    previous = Some(answer);
+                }
+                Err(output) => {
+                    println!("❌ Build or run failed:");
+                    println!("{}", output);
+                    println!("Previous: {}", previous.as_deref().unwrap_or("-"));
+                }
+            }
+            println!();
+            println!("Build and run took {:.2?}", elapsed);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Rebuild and run the day through `cargo run`, returning the bare answer or
+/// the captured error output.
+fn run_child(year: u32, day: u32, part: u8, use_example: bool, answers_path: &Path, root: &Path) -> Result<String, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--quiet", "-p", "runner", "--", "run"])
+        .arg(day.to_string())
+        .arg(part.to_string())
+        .arg(mode_name(use_example))
+        .args(["--quiet", "--no-gate", "--no-history"])
+        .arg("--year")
+        .arg(year.to_string())
+        .arg("--answers")
+        .arg(answers_path)
+        .output()
+        .map_err(|e| format!("Failed to start cargo: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+    // A mismatch against the registry still exits non-zero but prints the answer.
+    if stdout.is_empty() && stderr.is_empty() {
+        Err(format!("no answer produced ({})", output.status))
+    } else if stdout.is_empty() {
+        Err(stderr)
+    } else {
+        Ok(stdout)
+    }
+}
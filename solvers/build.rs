@@ -1,8 +1,103 @@
 use std::{fs, path::Path};
 
-fn main() {
-    let mut entries: Vec<(u32, String)> = fs::read_dir("src/days")
-        .expect("Failed to read src/days")
+/// A day's input-parsing function, e.g. `pub(crate) fn parse_points(input: &Input)`.
+struct Parser {
+    name: String,
+    /// Whether the parser also takes the part number (`part: u8`).
+    takes_part: bool,
+}
+
+/// Find the first crate-visible `parse_*` function in a day's source.
+fn find_parser(source: &str) -> Option<Parser> {
+    source.lines().find_map(|line| {
+        let rest = line
+            .strip_prefix("pub(crate) fn ")
+            .or_else(|| line.strip_prefix("pub fn "))?;
+        if !rest.starts_with("parse_") {
+            return None;
+        }
+        let (name, args) = rest.split_once('(')?;
+        Some(Parser {
+            name: name.to_string(),
+            takes_part: args.contains("part: u8"),
+        })
+    })
+}
+
+/// The puzzle title from a `//! Day N: Title` line in the module docs.
+fn find_title(source: &str) -> Option<String> {
+    source.lines().find_map(|line| {
+        let doc = line.strip_prefix("//!")?.trim();
+        let (day, title) = doc.strip_prefix("Day ")?.split_once(':')?;
+        day.trim().parse::<u32>().ok()?;
+        Some(title.trim().to_string()).filter(|t| !t.is_empty())
+    })
+}
+
+/// The `partN` functions a solution module defines.
+fn find_parts(source: &str) -> Vec<u8> {
+    [1, 2]
+        .into_iter()
+        .filter(|part| {
+            let signature = format!("pub fn part{}(", part);
+            source.lines().any(|line| line.trim_start().starts_with(&signature))
+        })
+        .collect()
+}
+
+/// The `///` doc comment of `pub fn partN`, without the slashes; attribute
+/// lines between the comment and the function are skipped.
+fn find_part_doc(source: &str, part: u8) -> Option<String> {
+    let signature = format!("pub fn part{}(", part);
+    let lines: Vec<&str> = source.lines().collect();
+    let index = lines.iter().position(|line| line.starts_with(&signature))?;
+    let mut doc: Vec<&str> = lines[..index]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .skip_while(|line| line.starts_with("#["))
+        .map_while(|line| line.strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+    doc.reverse();
+    Some(doc.join("\n")).filter(|doc| !doc.trim().is_empty())
+}
+
+/// A named alternative solution of a day, from `dayNN_<name>.rs`.
+struct Variant {
+    name: String,
+    module: String,
+    parser: Option<Parser>,
+    /// Parts the variant implements; the others are only in `dayNN.rs`.
+    parts: Vec<u8>,
+}
+
+/// Whether a day's module declares tunable parameters (`pub const PARAMS`).
+fn has_params(source: &str) -> bool {
+    source.lines().any(|line| line.starts_with("pub const PARAMS:"))
+}
+
+/// One `dayNN.rs` solution module and its variants.
+struct Day {
+    num: u32,
+    module: String,
+    parser: Option<Parser>,
+    title: Option<String>,
+    /// Doc comments of `part1` and `part2`.
+    docs: [Option<String>; 2],
+    params: bool,
+    variants: Vec<Variant>,
+}
+
+/// Year whose solutions live in `src/days` and whose inputs may sit directly
+/// in `inputs/`; must match `solvers::DEFAULT_YEAR`.
+const DEFAULT_YEAR: u32 = 2025;
+
+/// Find the `dayNN.rs` modules in `dir`, in day order, each with its
+/// `dayNN_<name>.rs` variants in name order.
+fn scan_days(dir: &Path) -> Vec<Day> {
+    let mut modules: Vec<(u32, Option<String>, String, String)> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", dir.display(), e))
         .filter_map(|entry| {
             let entry = entry.ok()?;
             let meta = entry.metadata().ok()?;
@@ -17,42 +112,335 @@ fn main() {
             }
 
             let stem = &name[..name.len() - 3]; // strip .rs
-            let num_str = &stem[3..];
+            let (num_str, variant) = match stem[3..].split_once('_') {
+                Some((num, variant)) => (num, Some(variant.to_string())),
+                None => (&stem[3..], None),
+            };
             let num: u32 = num_str.parse().ok()?;
+            if let Some(variant) = &variant {
+                let valid = variant.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+                assert!(valid && variant != "default", "{}: invalid variant name '{}'", name, variant);
+            }
 
-            Some((num, stem.to_string()))
+            let source = fs::read_to_string(entry.path()).ok()?;
+            Some((num, variant, stem.to_string(), source))
         })
         .collect();
+    modules.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+    let mut entries: Vec<Day> = Vec::new();
+    for (num, variant, module, source) in modules {
+        let Some(name) = variant else {
+            entries.push(Day {
+                num,
+                module,
+                parser: find_parser(&source),
+                title: find_title(&source),
+                docs: [find_part_doc(&source, 1), find_part_doc(&source, 2)],
+                params: has_params(&source),
+                variants: Vec::new(),
+            });
+            continue;
+        };
+        let day = entries
+            .last_mut()
+            .filter(|d| d.num == num)
+            .unwrap_or_else(|| panic!("{}.rs is a variant of day{:02}.rs, which does not exist", module, num));
+        day.variants.push(Variant {
+            name,
+            module,
+            parser: find_parser(&source),
+            parts: find_parts(&source),
+        });
+    }
+    entries
+}
+
+/// The `parse_day` arm for one module's parser.
+fn parse_arm(pattern: &str, module: &str, parser: &Parser) -> String {
+    let args = if parser.takes_part {
+        "input, part"
+    } else {
+        "input"
+    };
+    format!(
+        "        {} => {{\n            std::hint::black_box({}::{}({}));\n            true\n        }}\n",
+        pattern, module, parser.name, args
+    )
+}
 
-    entries.sort_by_key(|(n, _)| *n);
+/// A generated `pub fn` that matches `scrutinee` against `arms` and returns
+/// `fallback` otherwise. Without arms the body is just `fallback`, since a
+/// match with only a wildcard arm fails clippy; the arguments are then
+/// unused, as some may be anyway with `allow_unused`.
+fn function(signature: &str, scrutinee: &str, arms: &[String], fallback: &str, allow_unused: bool) -> String {
+    let allow = if allow_unused || arms.is_empty() {
+        "#[allow(unused_variables)]\n"
+    } else {
+        ""
+    };
+    if arms.is_empty() {
+        return format!("\n{}pub fn {} {{\n    {}\n}}\n", allow, signature, fallback);
+    }
+    let mut code = format!("\n{}pub fn {} {{\n    match {} {{\n", allow, signature, scrutinee);
+    for arm in arms {
+        code.push_str(arm);
+    }
+    code.push_str(&format!("        _ => {},\n    }}\n}}\n", fallback));
+    code
+}
 
+/// The `mod.rs` of one year's directory: module declarations, the list of
+/// implemented days, titles, part docs, parameters, variants and the
+/// dispatchers.
+fn dispatcher(entries: &[Day]) -> String {
     let mut code = String::new();
 
     code.push_str("// AUTO-GENERATED by build.rs. Do not edit manually.\n");
+    code.push_str("use crate::{input::Input, Param, Variant};\n\n");
 
     // Declare modules
-    for (_, mod_name) in &entries {
-        code.push_str(&format!("pub mod {};\n", mod_name));
+    for day in entries {
+        code.push_str(&format!("pub mod {};\n", day.module));
+        for variant in &day.variants {
+            code.push_str(&format!("pub mod {};\n", variant.module));
+        }
+    }
+
+    // Implemented days
+    code.push('\n');
+    let days: Vec<String> = entries.iter().map(|d| d.num.to_string()).collect();
+    code.push_str(&format!("pub const DAYS: &[u32] = &[{}];\n", days.join(", ")));
+
+    // Puzzle titles
+    let arms: Vec<String> = entries
+        .iter()
+        .filter_map(|day| Some(format!("        {} => Some({:?}),\n", day.num, day.title.as_ref()?)))
+        .collect();
+    code.push_str(&function("title(day: u32) -> Option<&'static str>", "day", &arms, "None", false));
+
+    // Doc comments of the parts
+    let mut arms = Vec::new();
+    for day in entries {
+        for (part, doc) in (1..).zip(&day.docs) {
+            if let Some(doc) = doc {
+                arms.push(format!("        ({}, {}) => Some({:?}),\n", day.num, part, doc));
+            }
+        }
     }
+    code.push_str(&function(
+        "part_doc(day: u32, part: u8) -> Option<&'static str>",
+        "(day, part)",
+        &arms,
+        "None",
+        false,
+    ));
+
+    // Declared parameters
+    let arms: Vec<String> = entries
+        .iter()
+        .filter(|d| d.params)
+        .map(|day| format!("        {} => {}::PARAMS,\n", day.num, day.module))
+        .collect();
+    code.push_str(&function("params(day: u32) -> &'static [Param]", "day", &arms, "&[]", false));
+
+    // Variants
+    let mut arms = Vec::new();
+    for day in entries.iter().filter(|d| !d.variants.is_empty()) {
+        let list: Vec<String> = day
+            .variants
+            .iter()
+            .map(|v| format!("Variant {{ name: {:?}, parts: &{:?} }}", v.name, v.parts))
+            .collect();
+        arms.push(format!("        {} => &[{}],\n", day.num, list.join(", ")));
+    }
+    code.push_str(&function("variants(day: u32) -> &'static [Variant]", "day", &arms, "&[]", false));
 
     // Dispatcher
-    code.push_str("\n");
-    code.push_str("pub fn run_day(day: u32, part: u8, use_example: bool) -> Option<String> {\n");
-    code.push_str("    match (day, part) {\n");
-    for (num, mod_name) in &entries {
-        code.push_str(&format!(
-            "        ({}, 1) => Some({}::part1(use_example)),\n",
-            num, mod_name
-        ));
-        code.push_str(&format!(
-            "        ({}, 2) => Some({}::part2(use_example)),\n",
-            num, mod_name
-        ));
-    }
-    code.push_str("        _ => None,\n");
-    code.push_str("    }\n");
-    code.push_str("}\n");
-
-    let out_path = Path::new("src/days/mod.rs");
-    fs::write(&out_path, code).expect("Failed to write src/days/mod.rs");
+    let mut arms = Vec::new();
+    for day in entries {
+        arms.push(format!("        ({}, None, 1) => Some({}::part1(input)),\n", day.num, day.module));
+        arms.push(format!("        ({}, None, 2) => Some({}::part2(input)),\n", day.num, day.module));
+        for variant in &day.variants {
+            for part in &variant.parts {
+                arms.push(format!(
+                    "        ({}, Some({:?}), {}) => Some({}::part{}(input)),\n",
+                    day.num, variant.name, part, variant.module, part
+                ));
+            }
+        }
+    }
+    code.push_str(&function(
+        "run_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> Option<String>",
+        "(day, variant, part)",
+        &arms,
+        "None",
+        false,
+    ));
+
+    // Parse-only dispatcher, for modules that expose their parsing step
+    let mut arms = Vec::new();
+    for day in entries {
+        if let Some(parser) = &day.parser {
+            arms.push(parse_arm(&format!("({}, None)", day.num), &day.module, parser));
+        }
+        for variant in &day.variants {
+            if let Some(parser) = &variant.parser {
+                let pattern = format!("({}, Some({:?}))", day.num, variant.name);
+                arms.push(parse_arm(&pattern, &variant.module, parser));
+            }
+        }
+    }
+    // Parsers that ignore the part leave `part` unused.
+    code.push_str(&function(
+        "parse_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> bool",
+        "(day, variant)",
+        &arms,
+        "false",
+        true,
+    ));
+
+    code
+}
+
+/// A year's solution directory under `src/`, and how `years.rs` refers to it.
+struct Year {
+    year: u32,
+    dir: String,
+    path: String,
+}
+
+fn main() {
+    let mut years = vec![Year {
+        year: DEFAULT_YEAR,
+        dir: "days".to_string(),
+        path: "crate::days".to_string(),
+    }];
+    for entry in fs::read_dir("src").expect("Failed to read src").flatten() {
+        let name = entry.file_name();
+        let Some(year) = name.to_str().and_then(|n| n.strip_prefix('y')).and_then(|y| y.parse::<u32>().ok()) else {
+            continue;
+        };
+        if !entry.path().is_dir() {
+            continue;
+        }
+        assert!(
+            year != DEFAULT_YEAR,
+            "src/y{} duplicates src/days, which already holds the {} solutions",
+            year,
+            DEFAULT_YEAR
+        );
+        years.push(Year {
+            year,
+            dir: format!("y{}", year),
+            path: format!("y{}", year),
+        });
+    }
+    years.sort_by_key(|y| y.year);
+
+    // `src/fixture` is a test-only year with one plain day, so that the code
+    // generated for a year without titles, parameters, variants or parse
+    // steps is compiled and linted too.
+    let dirs = years.iter().map(|y| y.dir.as_str()).chain(["fixture"]);
+    for dir in dirs {
+        let dir = Path::new("src").join(dir);
+        let code = dispatcher(&scan_days(&dir));
+        let out_path = dir.join("mod.rs");
+        fs::write(&out_path, code).unwrap_or_else(|e| panic!("Failed to write {}: {}", out_path.display(), e));
+    }
+
+    let mut code = String::new();
+    code.push_str("// AUTO-GENERATED by build.rs. Do not edit manually.\n");
+    code.push_str("use crate::{input::Input, Param, Variant};\n");
+
+    // Year modules; the default year is `crate::days`, declared in lib.rs
+    for year in years.iter().filter(|y| y.year != DEFAULT_YEAR) {
+        code.push_str(&format!("\n#[path = \"{}/mod.rs\"]\npub mod {};\n", year.dir, year.path));
+    }
+
+    code.push('\n');
+    let list: Vec<String> = years.iter().map(|y| y.year.to_string()).collect();
+    code.push_str(&format!("pub const YEARS: &[u32] = &[{}];\n", list.join(", ")));
+
+    // Per-year dispatch to each year's own functions
+    let functions = [
+        ("days(year: u32) -> &'static [u32]", "DAYS", "&[]"),
+        ("title(year: u32, day: u32) -> Option<&'static str>", "title(day)", "None"),
+        (
+            "part_doc(year: u32, day: u32, part: u8) -> Option<&'static str>",
+            "part_doc(day, part)",
+            "None",
+        ),
+        ("params(year: u32, day: u32) -> &'static [Param]", "params(day)", "&[]"),
+        ("variants(year: u32, day: u32) -> &'static [Variant]", "variants(day)", "&[]"),
+        (
+            "run_day(year: u32, day: u32, variant: Option<&str>, part: u8, input: &Input) -> Option<String>",
+            "run_day(day, variant, part, input)",
+            "None",
+        ),
+        (
+            "parse_day(year: u32, day: u32, variant: Option<&str>, part: u8, input: &Input) -> bool",
+            "parse_day(day, variant, part, input)",
+            "false",
+        ),
+    ];
+    for (signature, call, fallback) in functions {
+        code.push('\n');
+        code.push_str(&format!("pub fn {} {{\n", signature));
+        code.push_str("    match year {\n");
+        for year in &years {
+            code.push_str(&format!("        {} => {}::{},\n", year.year, year.path, call));
+        }
+        code.push_str(&format!("        _ => {},\n", fallback));
+        code.push_str("    }\n");
+        code.push_str("}\n");
+    }
+
+    fs::write("src/years.rs", code).expect("Failed to write src/years.rs");
+
+    println!("cargo:rustc-env=SOLVERS_FINGERPRINT={}", fingerprint());
+}
+
+/// Every file under `dir`, recursively, in a stable order.
+fn source_files(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", dir.display(), e))
+        .flatten()
+        .map(|e| e.path())
+        .collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            source_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// A hash of everything the solvers are built from: the manifest, this
+/// script, every source file (including the generated dispatchers) and the
+/// build profile, since overflow checks differ between debug and release.
+///
+/// Without `rerun-if-changed` lines Cargo reruns this script whenever a file
+/// in the package changes, so the fingerprint is fresh on every rebuild.
+fn fingerprint() -> String {
+    let mut files = vec![Path::new("Cargo.toml").to_path_buf(), Path::new("build.rs").to_path_buf()];
+    source_files(Path::new("src"), &mut files);
+
+    // FNV-1a, 64 bits.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut feed = |bytes: &[u8]| {
+        for &b in bytes {
+            hash = (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    for path in &files {
+        let contents = fs::read(path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+        feed(path.to_string_lossy().as_bytes());
+        feed(&(contents.len() as u64).to_le_bytes());
+        feed(&contents);
+    }
+    feed(std::env::var("PROFILE").unwrap_or_default().as_bytes());
+    format!("{:016x}", hash)
 }
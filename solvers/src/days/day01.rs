@@ -1,31 +1,43 @@
-use std::fs;
+//! Day 1: Secret Entrance
+
+use crate::{input::Input, Param};
+
+/// Puzzle constants, overridable with `-p name=value`.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "dial_size",
+        default: 100,
+        min: 1,
+        max: 1_000_000_000,
+        below: None,
+        help: "Number of positions on the dial",
+    },
+    Param {
+        name: "start",
+        default: 50,
+        min: 0,
+        max: 1_000_000_000,
+        below: Some("dial_size"),
+        help: "Position the dial starts at",
+    },
+];
 
 /// Day 01: count how many times the dial points at 0 after applying all rotations.
 ///
-/// - Dial values range from 0 to 99, inclusive.
-/// - The dial starts at 50.
+/// - Dial values range from 0 to 99, inclusive (`dial_size` positions).
+/// - The dial starts at 50 (`start`).
 /// - Each line of input is of the form `L<number>` or `R<number>`.
 /// - `L` means rotate toward lower numbers, `R` toward higher numbers.
 /// - The dial wraps around (modulo 100).
 /// - After each rotation, if the dial points at 0, increment the counter.
 ///
 /// This is the solution for **part 1**.
-pub fn part1(use_example: bool) -> String {
-    let day = 1u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
-
-    let input = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
-
-    solve_part1(&input)
+pub fn part1(input: &Input) -> String {
+    solve_part1(&input.text(), input.param("dial_size"), input.param("start"))
 }
 
-fn solve_part1(input: &str) -> String {
-    let mut position: i32 = 50; // starting position
+fn solve_part1(input: &str, size: i32, start: i32) -> String {
+    let mut position: i32 = start;
     let mut count_zero = 0u32;
 
     for line in input.lines() {
@@ -41,11 +53,11 @@ fn solve_part1(input: &str) -> String {
             .parse()
             .unwrap_or_else(|e| panic!("Invalid distance in rotation '{}': {}", line, e));
 
-        let steps = distance % 100; // rotating 100 steps is a full circle
+        let steps = distance % size; // rotating `size` steps is a full circle
 
         position = match dir {
-            "L" => (position - steps).rem_euclid(100),
-            "R" => (position + steps).rem_euclid(100),
+            "L" => (position - steps).rem_euclid(size),
+            "R" => (position + steps).rem_euclid(size),
             _ => panic!("Invalid direction in rotation '{}'", line),
         };
 
@@ -63,22 +75,12 @@ fn solve_part1(input: &str) -> String {
 /// That is, for each individual "click" while applying the rotations (including
 /// the final click that lands on the end position), count every time the dial
 /// is exactly at 0.
-pub fn part2(use_example: bool) -> String {
-    let day = 1u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
-
-    let input = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
-
-    solve_part2(&input)
+pub fn part2(input: &Input) -> String {
+    solve_part2(&input.text(), input.param("dial_size"), input.param("start"))
 }
 
-fn solve_part2(input: &str) -> String {
-    let mut position: i32 = 50; // starting position
+fn solve_part2(input: &str, size: i32, start: i32) -> String {
+    let mut position: i32 = start;
     let mut count_zero: u64 = 0;
 
     for line in input.lines() {
@@ -104,18 +106,18 @@ fn solve_part2(input: &str) -> String {
 
         // Count how many intermediate clicks (including the final one) land on 0.
         let hits = match dir {
-            "L" => count_hits_left(position, d),
-            "R" => count_hits_right(position, d),
+            "L" => count_hits_left(position, d, size),
+            "R" => count_hits_right(position, d, size),
             _ => panic!("Invalid direction in rotation '{}'", line),
         };
 
         count_zero += hits;
 
-        // Update final position (only depends on distance modulo 100).
-        let steps = (distance % 100) as i32;
+        // Update final position (only depends on distance modulo the dial size).
+        let steps = (distance % size as i64) as i32;
         position = match dir {
-            "L" => (position - steps).rem_euclid(100),
-            "R" => (position + steps).rem_euclid(100),
+            "L" => (position - steps).rem_euclid(size),
+            "R" => (position + steps).rem_euclid(size),
             _ => unreachable!("direction already validated"),
         };
     }
@@ -123,32 +125,34 @@ fn solve_part2(input: &str) -> String {
     count_zero.to_string()
 }
 
-fn count_hits_right(start: i32, distance: u64) -> u64 {
-    // Positions visited: (start + k) mod 100 for k in 1..=distance.
-    // Solve (start + k) ≡ 0 (mod 100) => k ≡ (100 - start) (mod 100).
-    let p = start.rem_euclid(100) as u64;
-    let mut base = (100 - p) % 100; // in [0,99]
+fn count_hits_right(start: i32, distance: u64, size: i32) -> u64 {
+    // Positions visited: (start + k) mod size for k in 1..=distance.
+    // Solve (start + k) ≡ 0 (mod size) => k ≡ (size - start) (mod size).
+    let size = size as u64;
+    let p = start.rem_euclid(size as i32) as u64;
+    let mut base = (size - p) % size; // in [0,size)
     if base == 0 {
-        base = 100; // first time we hit 0 going right
+        base = size; // first time we hit 0 going right
     }
     if base > distance {
         0
     } else {
-        1 + (distance - base) / 100
+        1 + (distance - base) / size
     }
 }
 
-fn count_hits_left(start: i32, distance: u64) -> u64 {
-    // Positions visited: (start - k) mod 100 for k in 1..=distance.
-    // Solve (start - k) ≡ 0 (mod 100) => k ≡ start (mod 100).
-    let p = start.rem_euclid(100) as u64;
-    let mut base = p % 100; // in [0,99]
+fn count_hits_left(start: i32, distance: u64, size: i32) -> u64 {
+    // Positions visited: (start - k) mod size for k in 1..=distance.
+    // Solve (start - k) ≡ 0 (mod size) => k ≡ start (mod size).
+    let size = size as u64;
+    let p = start.rem_euclid(size as i32) as u64;
+    let mut base = p % size; // in [0,size)
     if base == 0 {
-        base = 100; // first time we hit 0 going left
+        base = size; // first time we hit 0 going left
     }
     if base > distance {
         0
     } else {
-        1 + (distance - base) / 100
+        1 + (distance - base) / size
     }
 }
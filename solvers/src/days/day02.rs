@@ -1,15 +1,9 @@
-use std::fs;
+//! Day 2: Gift Shop
 
-fn parse_ranges(use_example: bool) -> Vec<(u64, u64)> {
-    let day = 2u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
+use crate::input::Input;
 
-    let input = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+pub(crate) fn parse_ranges(input: &Input) -> Vec<(u64, u64)> {
+    let input = input.text();
 
     // Remove all whitespace (including newlines) and split by commas
     let cleaned: String = input.chars().filter(|c| !c.is_whitespace()).collect();
@@ -33,6 +27,7 @@ fn parse_ranges(use_example: bool) -> Vec<(u64, u64)> {
         .collect()
 }
 
+#[allow(clippy::manual_is_multiple_of, clippy::op_ref, clippy::manual_find, clippy::needless_return)]
 fn next_invalid_id(start: u64, max: u64, part: u8) -> Option<u64> {
     fn is_invalid_id_part1(n: u64) -> bool {
         let s = n.to_string();
@@ -93,6 +88,7 @@ fn next_invalid_id(start: u64, max: u64, part: u8) -> Option<u64> {
     return None;
 }
 
+#[allow(clippy::needless_return)]
 fn sum_invalid_ids(start: u64, end: u64, part: u8) -> u64 {
     let mut sum: u64 = 0;
     let mut current_id: u64 = start;
@@ -103,17 +99,17 @@ fn sum_invalid_ids(start: u64, end: u64, part: u8) -> u64 {
     return sum;
 }
 
-pub fn part1(use_example: bool) -> String {
+pub fn part1(input: &Input) -> String {
     let mut sum: u64 = 0;
-    for range in parse_ranges(use_example).iter() {
+    for range in parse_ranges(input).iter() {
         sum += sum_invalid_ids(range.0, range.1, 1);
     }
     sum.to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
+pub fn part2(input: &Input) -> String {
     let mut sum: u64 = 0;
-    for range in parse_ranges(use_example).iter() {
+    for range in parse_ranges(input).iter() {
         sum += sum_invalid_ids(range.0, range.1, 2);
     }
     sum.to_string()
@@ -0,0 +1,71 @@
+//! Day 2 variant: generates the repeated-block IDs in each range directly
+//! instead of testing every ID in it.
+
+use std::collections::BTreeSet;
+
+use super::day02::parse_ranges;
+use crate::input::Input;
+
+fn digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// Every ID in `start..=end` that is a block of digits repeated at least
+/// twice (exactly twice for part 1).
+///
+/// An ID of `len` digits made of a `block`-digit seed repeated is the seed
+/// times `10^(len - block) + ... + 10^block + 1`, so the seeds that land in
+/// the range can be computed rather than searched for.
+fn invalid_ids(start: u64, end: u64, part: u8) -> BTreeSet<u64> {
+    let mut ids = BTreeSet::new();
+    for len in digits(start)..=digits(end) {
+        for block in 1..=len / 2 {
+            if !len.is_multiple_of(block) || (part == 1 && len != 2 * block) {
+                continue;
+            }
+            // 10^20 overflows a u64, though the multiplier itself always fits.
+            let multiplier = ((10u128.pow(len) - 1) / (10u128.pow(block) - 1)) as u64;
+            let first = 10u64.pow(block - 1).max(start.div_ceil(multiplier));
+            let last = (10u64.pow(block) - 1).min(end / multiplier);
+            // Seeds like 11 and 1 give the same ID; the set keeps one.
+            ids.extend((first..=last).map(|seed| seed * multiplier));
+        }
+    }
+    ids
+}
+
+fn solve(input: &Input, part: u8) -> String {
+    // IDs near u64::MAX add up to more than a u64 holds.
+    let sum: u128 = parse_ranges(input)
+        .iter()
+        .flat_map(|&(start, end)| invalid_ids(start, end, part))
+        .map(u128::from)
+        .sum();
+    sum.to_string()
+}
+
+pub fn part1(input: &Input) -> String {
+    solve(input, 1)
+}
+
+pub fn part2(input: &Input) -> String {
+    solve(input, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Source;
+
+    #[test]
+    fn twenty_digit_ranges_do_not_overflow() {
+        assert!(invalid_ids(18_446_744_073_709_551_600, u64::MAX, 2).is_empty());
+        let id = 10_101_010_101_010_101_010;
+        assert_eq!(invalid_ids(id - 10, id + 10, 1), BTreeSet::from([id]));
+        assert_eq!(invalid_ids(id - 10, id + 10, 2), BTreeSet::from([id]));
+
+        let text = "10101010101010101010-10101010101010101010,11111111111111111111-11111111111111111111";
+        let input = Input::conventional(2025, 2, false).with_source(Source::Text(text.to_string()));
+        assert_eq!(part2(&input), "21212121212121212121");
+    }
+}
@@ -1,26 +1,43 @@
-use std::fs;
+//! Day 3: Lobby
 
-fn parse_batteries(use_example: bool) -> Vec<String> {
-    let day = 3u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
+use crate::{input::Input, Param};
 
-    fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+/// Puzzle constants, overridable with `-p name=value`.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "part1_digits",
+        default: 2,
+        min: 1,
+        // More digits overflow the u64 joltage.
+        max: 19,
+        below: None,
+        help: "Batteries turned on per bank in part 1",
+    },
+    Param {
+        name: "part2_digits",
+        default: 12,
+        min: 1,
+        max: 19,
+        below: None,
+        help: "Batteries turned on per bank in part 2",
+    },
+];
+
+pub(crate) fn parse_batteries(input: &Input) -> Vec<String> {
+    input.text()
         .split('\n')
         .filter(|s| !s.is_empty())
         .map(|s| s.to_string())
         .collect()
 }
 
+#[allow(clippy::needless_range_loop)]
 fn calc_joltage(battery: &str, n: usize) -> u64 {
     let digits: Vec<u8> = battery
         .chars()
         .map(|c| c.to_digit(10).unwrap() as u8)
         .collect();
+    assert!(n <= digits.len(), "Cannot turn on {} batteries in a bank of {}", n, digits.len());
     
     let mut joltage: Vec<u8> = Vec::new();
     let mut last_pos: i32 = -1;
@@ -54,18 +71,20 @@ fn calc_joltage(battery: &str, n: usize) -> u64 {
     result
 }
 
-pub fn part1(use_example: bool) -> String {
+pub fn part1(input: &Input) -> String {
+    let digits = input.param("part1_digits");
     let mut sum: u64 = 0;
-    for battery in parse_batteries(use_example).iter() {
-        sum += calc_joltage(battery, 2);
+    for battery in parse_batteries(input).iter() {
+        sum += calc_joltage(battery, digits);
     }
     sum.to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
+pub fn part2(input: &Input) -> String {
+    let digits = input.param("part2_digits");
     let mut sum: u64 = 0;
-    for battery in parse_batteries(use_example).iter() {
-        sum += calc_joltage(battery, 12);
+    for battery in parse_batteries(input).iter() {
+        sum += calc_joltage(battery, digits);
     }
     sum.to_string()
 }
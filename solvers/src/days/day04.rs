@@ -1,20 +1,14 @@
-use std::fs;
+//! Day 4: Printing Department
+
+use crate::input::Input;
 use std::collections::{
     HashMap, 
     HashSet
 };
 
-fn parse_map(use_example: bool) -> HashMap<(i32, i32), char> {
-    let day = 4u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
-
+pub(crate) fn parse_map(input: &Input) -> HashMap<(i32, i32), char> {
     let mut map: HashMap<(i32, i32), char> = HashMap::new();
-    let data = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    let data = input.text();
 
     for (x, line) in data.split('\n').enumerate() {
         if line.is_empty() {
@@ -28,8 +22,8 @@ fn parse_map(use_example: bool) -> HashMap<(i32, i32), char> {
     map
 }
 
-pub fn part1(use_example: bool) -> String {
-    let map = parse_map(use_example);
+pub fn part1(input: &Input) -> String {
+    let map = parse_map(input);
 
     let directions = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
     let is_reachable = |node: (i32, i32)| -> bool {
@@ -53,11 +47,11 @@ pub fn part1(use_example: bool) -> String {
     sum.to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
+pub fn part2(input: &Input) -> String {
     let mut dots: HashSet<(i32, i32)> = HashSet::new();
     let mut ats: HashSet<(i32, i32)> = HashSet::new();
 
-    for (key, &value) in parse_map(use_example).iter() {
+    for (key, &value) in parse_map(input).iter() {
         match value {
             '.' => { dots.insert(*key); },
             '@' => { ats.insert(*key); },
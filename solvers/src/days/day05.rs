@@ -1,18 +1,12 @@
-use std::fs;
+//! Day 5: Cafeteria
 
-fn parse_products(use_example: bool) -> (Vec<u64>, Vec<(u64, u64)>) {
-    let day = 5u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
+use crate::input::Input;
 
+pub(crate) fn parse_products(input: &Input) -> (Vec<u64>, Vec<(u64, u64)>) {
     let mut fresh_ranges: Vec<(u64, u64)> = Vec::new();
     let mut ingredients: Vec<u64> = Vec::new();
 
-    let data = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    let data = input.text();
     for line in data.split('\n') {
         if line.is_empty() {
             continue;
@@ -32,8 +26,8 @@ fn parse_products(use_example: bool) -> (Vec<u64>, Vec<(u64, u64)>) {
     (ingredients, fresh_ranges)
 }
 
-pub fn part1(use_example: bool) -> String {
-    let (ingredients, fresh_ranges) = parse_products(use_example);
+pub fn part1(input: &Input) -> String {
+    let (ingredients, fresh_ranges) = parse_products(input);
     let is_fresh = |ingredient: u64| -> bool {
         for (start, end) in fresh_ranges.iter() {
             if ingredient >= *start && ingredient <= *end {
@@ -53,9 +47,9 @@ pub fn part1(use_example: bool) -> String {
     sum.to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
+pub fn part2(input: &Input) -> String {
     let mut merged_ranges: Vec<(u64, u64)> = Vec::new();
-    for range in parse_products(use_example).1.iter() {
+    for range in parse_products(input).1.iter() {
         let mut new_range = *range;
         merged_ranges.retain(|m_range| {
             if new_range.0 <= m_range.1 && m_range.0 <= new_range.1 {
@@ -1,15 +1,9 @@
-use std::fs;
+//! Day 6: Trash Compactor
 
-fn parse_equasions(use_example: bool, part: u8) -> Vec<(char, Vec<u64>)> {
-    let day = 6u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
+use crate::input::Input;
 
-    let text = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+pub(crate) fn parse_equasions(input: &Input, part: u8) -> Vec<(char, Vec<u64>)> {
+    let text = input.text();
     let data = text.split('\n').collect::<Vec<&str>>();
 
     if part == 1 {
@@ -64,6 +58,7 @@ fn parse_equasions(use_example: bool, part: u8) -> Vec<(char, Vec<u64>)> {
     }
 }
 
+#[allow(clippy::ptr_arg)]
 fn calculate_equasion(op: char, vals: &Vec<u64>) -> u64 {
     match op {
         '+' => vals.iter().sum(),
@@ -72,16 +67,16 @@ fn calculate_equasion(op: char, vals: &Vec<u64>) -> u64 {
     }
 }
 
-pub fn part1(use_example: bool) -> String {  
-    parse_equasions(use_example, 1)
+pub fn part1(input: &Input) -> String {  
+    parse_equasions(input, 1)
         .iter()
         .map(|(op, vals)| calculate_equasion(*op, vals))
         .sum::<u64>()
         .to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
-    parse_equasions(use_example, 2)
+pub fn part2(input: &Input) -> String {
+    parse_equasions(input, 2)
         .iter()
         .map(|(op, vals)| calculate_equasion(*op, vals))
         .sum::<u64>()
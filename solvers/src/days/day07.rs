@@ -1,19 +1,13 @@
-use std::fs;
+//! Day 7: Laboratories
+
+use crate::input::Input;
 use std::collections::{HashSet, VecDeque};
 
 
 type Point = (u64, u64);
 
-fn parse_splitters(use_example: bool) -> (Point, HashSet<Point>) {
-    let day = 7u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
-
-    let text = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+pub(crate) fn parse_splitters(input: &Input) -> (Point, HashSet<Point>) {
+    let text = input.text();
 
     let mut points = HashSet::new();
     let mut start = (0, 0);
@@ -84,12 +78,12 @@ fn count_timelines(start: Point, splitters: &HashSet<Point>) -> u64 {
 
 
 
-pub fn part1(use_example: bool) -> String {  
-    let (start, splitters) = parse_splitters(use_example);
+pub fn part1(input: &Input) -> String {  
+    let (start, splitters) = parse_splitters(input);
     count_splits(start, &splitters).to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
-    let (start, splitters) = parse_splitters(use_example);
+pub fn part2(input: &Input) -> String {
+    let (start, splitters) = parse_splitters(input);
     count_timelines(start, &splitters).to_string()
 }
@@ -1,9 +1,22 @@
-use std::fs;
+//! Day 8: Playground
+
+use crate::{input::Input, Param};
 use std::collections::{BinaryHeap, HashSet};
 use std::cmp::Reverse;
 
+/// Puzzle constants, overridable with `-p name=value`; the example's value
+/// is in `day08_example.toml`.
+pub const PARAMS: &[Param] = &[Param {
+    name: "connections",
+    default: 1000,
+    min: 1,
+    max: 1_000_000_000,
+    below: None,
+    help: "Number of shortest connections to make in part 1",
+}];
+
 #[derive(Debug)]
-struct Point {
+pub(crate) struct Point {
     x: u64,
     y: u64,
     z: u64,
@@ -21,16 +34,8 @@ impl Point {
     }
 }
 
-fn parse_points(use_example: bool) -> Vec<Point> {
-    let day = 8u8;
-    let path = if use_example {
-        format!("inputs/day{day:02}_example.txt")
-    } else {
-        format!("inputs/day{day:02}.txt")
-    };
-
-    let text = fs::read_to_string(&path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+pub(crate) fn parse_points(input: &Input) -> Vec<Point> {
+    let text = input.text();
 
     let mut points = Vec::new();
 
@@ -48,6 +53,7 @@ fn parse_points(use_example: bool) -> Vec<Point> {
     points
 }
 
+#[allow(clippy::ptr_arg)]
 fn make_min_circuits(points: &Vec<Point>, n_connections: u64) -> u64{
     // get the smallest distances
     let mut queue = BinaryHeap::new();
@@ -124,6 +130,7 @@ fn make_min_circuits(points: &Vec<Point>, n_connections: u64) -> u64{
     product
 }
 
+#[allow(clippy::ptr_arg)]
 fn make_mst(points: &Vec<Point>) -> u64 {
     let mut visited: HashSet<usize> = HashSet::new();
     let mut min_heap = BinaryHeap::new();
@@ -151,15 +158,12 @@ fn make_mst(points: &Vec<Point>) -> u64 {
     last_two_product
 }
 
-pub fn part1(use_example: bool) -> String {  
-    let points = parse_points(use_example);
-    let n_connections = match use_example {
-        true => 10,
-        false => 1000,
-    };
+pub fn part1(input: &Input) -> String {  
+    let points = parse_points(input);
+    let n_connections = input.param("connections");
     make_min_circuits(&points, n_connections).to_string()
 }
 
-pub fn part2(use_example: bool) -> String {
-    let points = parse_points(use_example);
+pub fn part2(input: &Input) -> String {
+    let points = parse_points(input);
     make_mst(&points).to_string()}
@@ -1,6 +1,9 @@
 // AUTO-GENERATED by build.rs. Do not edit manually.
+use crate::{input::Input, Param, Variant};
+
 pub mod day01;
 pub mod day02;
+pub mod day02_fast;
 pub mod day03;
 pub mod day04;
 pub mod day05;
@@ -8,24 +11,101 @@ pub mod day06;
 pub mod day07;
 pub mod day08;
 
-pub fn run_day(day: u32, part: u8, use_example: bool) -> Option<String> {
+pub const DAYS: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8];
+
+pub fn title(day: u32) -> Option<&'static str> {
+    match day {
+        1 => Some("Secret Entrance"),
+        2 => Some("Gift Shop"),
+        3 => Some("Lobby"),
+        4 => Some("Printing Department"),
+        5 => Some("Cafeteria"),
+        6 => Some("Trash Compactor"),
+        7 => Some("Laboratories"),
+        8 => Some("Playground"),
+        _ => None,
+    }
+}
+
+pub fn part_doc(day: u32, part: u8) -> Option<&'static str> {
     match (day, part) {
-        (1, 1) => Some(day01::part1(use_example)),
-        (1, 2) => Some(day01::part2(use_example)),
-        (2, 1) => Some(day02::part1(use_example)),
-        (2, 2) => Some(day02::part2(use_example)),
-        (3, 1) => Some(day03::part1(use_example)),
-        (3, 2) => Some(day03::part2(use_example)),
-        (4, 1) => Some(day04::part1(use_example)),
-        (4, 2) => Some(day04::part2(use_example)),
-        (5, 1) => Some(day05::part1(use_example)),
-        (5, 2) => Some(day05::part2(use_example)),
-        (6, 1) => Some(day06::part1(use_example)),
-        (6, 2) => Some(day06::part2(use_example)),
-        (7, 1) => Some(day07::part1(use_example)),
-        (7, 2) => Some(day07::part2(use_example)),
-        (8, 1) => Some(day08::part1(use_example)),
-        (8, 2) => Some(day08::part2(use_example)),
+        (1, 1) => Some("Day 01: count how many times the dial points at 0 after applying all rotations.\n\n- Dial values range from 0 to 99, inclusive (`dial_size` positions).\n- The dial starts at 50 (`start`).\n- Each line of input is of the form `L<number>` or `R<number>`.\n- `L` means rotate toward lower numbers, `R` toward higher numbers.\n- The dial wraps around (modulo 100).\n- After each rotation, if the dial points at 0, increment the counter.\n\nThis is the solution for **part 1**."),
+        (1, 2) => Some("Day 01, part 2: count how many times any click causes the dial to point at 0,\nincluding intermediate positions during rotations.\n\nThat is, for each individual \"click\" while applying the rotations (including\nthe final click that lands on the end position), count every time the dial\nis exactly at 0."),
         _ => None,
     }
 }
+
+pub fn params(day: u32) -> &'static [Param] {
+    match day {
+        1 => day01::PARAMS,
+        3 => day03::PARAMS,
+        8 => day08::PARAMS,
+        _ => &[],
+    }
+}
+
+pub fn variants(day: u32) -> &'static [Variant] {
+    match day {
+        2 => &[Variant { name: "fast", parts: &[1, 2] }],
+        _ => &[],
+    }
+}
+
+pub fn run_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> Option<String> {
+    match (day, variant, part) {
+        (1, None, 1) => Some(day01::part1(input)),
+        (1, None, 2) => Some(day01::part2(input)),
+        (2, None, 1) => Some(day02::part1(input)),
+        (2, None, 2) => Some(day02::part2(input)),
+        (2, Some("fast"), 1) => Some(day02_fast::part1(input)),
+        (2, Some("fast"), 2) => Some(day02_fast::part2(input)),
+        (3, None, 1) => Some(day03::part1(input)),
+        (3, None, 2) => Some(day03::part2(input)),
+        (4, None, 1) => Some(day04::part1(input)),
+        (4, None, 2) => Some(day04::part2(input)),
+        (5, None, 1) => Some(day05::part1(input)),
+        (5, None, 2) => Some(day05::part2(input)),
+        (6, None, 1) => Some(day06::part1(input)),
+        (6, None, 2) => Some(day06::part2(input)),
+        (7, None, 1) => Some(day07::part1(input)),
+        (7, None, 2) => Some(day07::part2(input)),
+        (8, None, 1) => Some(day08::part1(input)),
+        (8, None, 2) => Some(day08::part2(input)),
+        _ => None,
+    }
+}
+
+#[allow(unused_variables)]
+pub fn parse_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> bool {
+    match (day, variant) {
+        (2, None) => {
+            std::hint::black_box(day02::parse_ranges(input));
+            true
+        }
+        (3, None) => {
+            std::hint::black_box(day03::parse_batteries(input));
+            true
+        }
+        (4, None) => {
+            std::hint::black_box(day04::parse_map(input));
+            true
+        }
+        (5, None) => {
+            std::hint::black_box(day05::parse_products(input));
+            true
+        }
+        (6, None) => {
+            std::hint::black_box(day06::parse_equasions(input, part));
+            true
+        }
+        (7, None) => {
+            std::hint::black_box(day07::parse_splitters(input));
+            true
+        }
+        (8, None) => {
+            std::hint::black_box(day08::parse_points(input));
+            true
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,9 @@
+use crate::input::Input;
+
+pub fn part1(input: &Input) -> String {
+    input.text().lines().count().to_string()
+}
+
+pub fn part2(input: &Input) -> String {
+    input.text().len().to_string()
+}
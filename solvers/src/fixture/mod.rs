@@ -0,0 +1,39 @@
+// AUTO-GENERATED by build.rs. Do not edit manually.
+use crate::{input::Input, Param, Variant};
+
+pub mod day01;
+
+pub const DAYS: &[u32] = &[1];
+
+#[allow(unused_variables)]
+pub fn title(day: u32) -> Option<&'static str> {
+    None
+}
+
+#[allow(unused_variables)]
+pub fn part_doc(day: u32, part: u8) -> Option<&'static str> {
+    None
+}
+
+#[allow(unused_variables)]
+pub fn params(day: u32) -> &'static [Param] {
+    &[]
+}
+
+#[allow(unused_variables)]
+pub fn variants(day: u32) -> &'static [Variant] {
+    &[]
+}
+
+pub fn run_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> Option<String> {
+    match (day, variant, part) {
+        (1, None, 1) => Some(day01::part1(input)),
+        (1, None, 2) => Some(day01::part2(input)),
+        _ => None,
+    }
+}
+
+#[allow(unused_variables)]
+pub fn parse_day(day: u32, variant: Option<&str>, part: u8, input: &Input) -> bool {
+    false
+}
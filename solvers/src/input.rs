@@ -0,0 +1,172 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// Directory holding the conventional inputs, set once by the runner.
+static INPUTS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Resolve conventional inputs against `dir` instead of `inputs/` in the
+/// current directory. Only the first call has an effect.
+pub fn set_inputs_dir(dir: PathBuf) {
+    let _ = INPUTS_DIR.set(dir);
+}
+
+/// The directory conventional inputs are read from.
+pub fn inputs_dir() -> &'static Path {
+    INPUTS_DIR.get_or_init(|| PathBuf::from("inputs"))
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The conventional location: `inputs/YYYY/dayNN.txt` or
+    /// `inputs/YYYY/dayNN_example.txt` (see [`conventional_path`]).
+    Conventional,
+    /// An arbitrary file.
+    Path(PathBuf),
+    /// Standard input, read to the end.
+    Stdin,
+    /// Input text given directly.
+    Text(String),
+}
+
+/// The puzzle input for one day.
+///
+/// `example` records whether this is an example-sized input, for the few
+/// days whose puzzle constants differ between the example and the real input.
+/// `variant` names the solution variant that should solve it, if not the
+/// day's main module. `params` overrides the day's tunable parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    pub year: u32,
+    pub day: u32,
+    pub example: bool,
+    pub source: Source,
+    pub variant: Option<String>,
+    pub params: BTreeMap<String, i64>,
+}
+
+impl Input {
+    /// The checked-in input for `year`/`day` at its conventional location.
+    pub fn conventional(year: u32, day: u32, example: bool) -> Self {
+        Input {
+            year,
+            day,
+            example,
+            source: Source::Conventional,
+            variant: None,
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// The same year, day and input kind, read from a different source.
+    pub fn with_source(self, source: Source) -> Self {
+        Input { source, ..self }
+    }
+
+    /// The same input, solved by `variant` (`None` for the main module).
+    pub fn with_variant(self, variant: Option<String>) -> Self {
+        Input { variant, ..self }
+    }
+
+    /// The same input, with `params` overriding the day's parameters.
+    pub fn with_params(self, params: BTreeMap<String, i64>) -> Self {
+        Input { params, ..self }
+    }
+
+    /// The value of one of the day's declared parameters: an override from
+    /// `params`, else for an example the value in its metadata file (see
+    /// [`metadata_path`](crate::params::metadata_path)), else the default.
+    ///
+    /// Panics if the day does not declare `name`, or the value is outside its
+    /// declared bounds or does not fit `T`.
+    #[track_caller]
+    pub fn param<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let Some(declared) = crate::params(self.year, self.day).iter().find(|p| p.name == name) else {
+            panic!("Day {} declares no parameter '{}'", self.day, name);
+        };
+
+        let from_metadata = || {
+            let path = crate::params::metadata_path(&self.path()?);
+            crate::params::read_metadata(&path)
+                .into_iter()
+                .find_map(|(key, value)| (key == name).then_some(value))
+        };
+        let value = match self.params.get(name) {
+            Some(&value) => value,
+            None => self.example.then(from_metadata).flatten().unwrap_or(declared.default),
+        };
+        if let Err(e) = declared.check(value, |other| self.param(other)) {
+            panic!("Day {}: {}", self.day, e);
+        }
+        match T::try_from(value) {
+            Ok(value) => value,
+            Err(_) => panic!("Parameter {} = {} is out of range", name, value),
+        }
+    }
+
+    /// The file this input is read from, if it comes from a file.
+    pub fn path(&self) -> Option<PathBuf> {
+        match &self.source {
+            Source::Conventional => Some(conventional_path(self.year, self.day, self.example)),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin | Source::Text(_) => None,
+        }
+    }
+
+    /// Read the full input text.
+    pub fn read(&self) -> io::Result<String> {
+        match &self.source {
+            Source::Conventional | Source::Path(_) => {
+                fs::read_to_string(self.path().expect("file-backed source"))
+            }
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+            Source::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// Read the full input text, panicking with the input's location on failure.
+    pub fn text(&self) -> String {
+        self.read()
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", self, e))
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Source::Conventional | Source::Path(_) => {
+                write!(f, "{}", self.path().expect("file-backed source").display())
+            }
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Text(_) => write!(f, "<inline input>"),
+        }
+    }
+}
+
+/// `inputs/YYYY/dayNN.txt`, or `inputs/YYYY/dayNN_example.txt` for the
+/// example, under the configured inputs directory.
+///
+/// Inputs for [`DEFAULT_YEAR`](crate::DEFAULT_YEAR) sit directly in `inputs/`
+/// unless an `inputs/YYYY/` directory exists for it.
+pub fn conventional_path(year: u32, day: u32, example: bool) -> PathBuf {
+    let year_dir = inputs_dir().join(year.to_string());
+    let dir = if year == crate::DEFAULT_YEAR && !year_dir.is_dir() {
+        inputs_dir().to_path_buf()
+    } else {
+        year_dir
+    };
+    if example {
+        dir.join(format!("day{day:02}_example.txt"))
+    } else {
+        dir.join(format!("day{day:02}.txt"))
+    }
+}
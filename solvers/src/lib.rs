@@ -1,8 +1,107 @@
 pub mod days;
+#[cfg(test)]
+mod fixture;
+pub mod input;
+pub mod params;
+mod years;
 
-/// Run the solution for a given day.
+pub use input::{Input, Source};
+pub use params::Param;
+
+/// Year whose solutions live in `src/days`; other years live in `src/yYYYY`.
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// Hash of the sources and build profile this crate was compiled from. It
+/// changes whenever the solvers are rebuilt with different code, so answers
+/// stored under it never outlive the code that produced them.
+pub const BUILD_FINGERPRINT: &str = env!("SOLVERS_FINGERPRINT");
+
+/// A named alternative solution of a day, kept next to the main one in a
+/// `dayNN_<name>.rs` module, e.g. a brute-force and an optimized version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variant {
+    pub name: &'static str,
+    /// Parts the variant implements.
+    pub parts: &'static [u8],
+}
+
+/// Years that have a solution directory, in ascending order.
+pub fn years() -> &'static [u32] {
+    years::YEARS
+}
+
+/// Run the solution for a given day on its conventional input.
 ///
 /// Returns `Some(answer)` if the day is implemented, or `None` otherwise.
-pub fn run_day(day: u32, part: u8, use_example: bool) -> Option<String> {
-    days::run_day(day, part, use_example)
+pub fn run_day(year: u32, day: u32, part: u8, use_example: bool) -> Option<String> {
+    run_input(part, &Input::conventional(year, day, use_example))
+}
+
+/// Run the solution for `input.year`/`input.day` on the given input, using
+/// `input.variant` if set.
+///
+/// Returns `Some(answer)` if the day (or the variant's part) is implemented,
+/// or `None` otherwise.
+pub fn run_input(part: u8, input: &Input) -> Option<String> {
+    years::run_day(input.year, input.day, input.variant.as_deref(), part, input)
+}
+
+/// Days of `year` that have a solution module, in ascending order.
+pub fn implemented_days(year: u32) -> &'static [u32] {
+    years::days(year)
+}
+
+/// The puzzle title of a day, from a `//! Day N: Title` line in its module docs.
+pub fn title(year: u32, day: u32) -> Option<&'static str> {
+    years::title(year, day)
+}
+
+/// The doc comment of a day's `partN` function, without the `///` markers.
+pub fn part_doc(year: u32, day: u32, part: u8) -> Option<&'static str> {
+    years::part_doc(year, day, part)
+}
+
+/// The variants of a day besides its `dayNN.rs` solution, in name order.
+pub fn variants(year: u32, day: u32) -> &'static [Variant] {
+    years::variants(year, day)
+}
+
+/// The tunable parameters a day declares in its `PARAMS` list.
+pub fn params(year: u32, day: u32) -> &'static [Param] {
+    years::params(year, day)
+}
+
+/// Run only the input-parsing step of a day, discarding the result.
+///
+/// Days opt in by exposing a `pub(crate) fn parse_*(input: &Input)`
+/// (optionally also taking `part: u8`). Returns `false` for days without one.
+pub fn parse_day(year: u32, day: u32, part: u8, use_example: bool) -> bool {
+    parse_input(part, &Input::conventional(year, day, use_example))
+}
+
+/// Run only the input-parsing step of `input.day` (or of `input.variant`)
+/// on the given input.
+pub fn parse_input(part: u8, input: &Input) -> bool {
+    years::parse_day(input.year, input.day, input.variant.as_deref(), part, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The fixture year's dispatchers have nothing to match but day 1's parts.
+    #[test]
+    fn generated_code_for_a_plain_year() {
+        let input = Input::conventional(DEFAULT_YEAR, 1, true).with_source(Source::Text("a\nb\n".to_string()));
+        assert_eq!(fixture::DAYS, [1]);
+        assert_eq!(fixture::run_day(1, None, 1, &input).as_deref(), Some("2"));
+        assert_eq!(fixture::run_day(1, None, 2, &input).as_deref(), Some("4"));
+        assert_eq!(fixture::run_day(1, Some("fast"), 1, &input), None);
+        assert_eq!(fixture::run_day(2, None, 1, &input), None);
+        assert!(!fixture::parse_day(1, None, 1, &input));
+        assert_eq!(fixture::title(1), None);
+        assert_eq!(fixture::part_doc(1, 1), None);
+        assert!(fixture::params(1).is_empty());
+        assert!(fixture::variants(1).is_empty());
+    }
 }
@@ -0,0 +1,77 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A tunable puzzle constant, declared by a day in its `PARAMS` list and
+/// read with [`Input::param`](crate::Input::param).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Value for the real input, unless overridden.
+    pub default: i64,
+    /// Smallest value the solver handles.
+    pub min: i64,
+    /// Largest value the solver handles.
+    pub max: i64,
+    /// Another of the day's parameters that the value must be smaller than,
+    /// such as the size of a dial for a position on it.
+    pub below: Option<&'static str>,
+    pub help: &'static str,
+}
+
+impl Param {
+    /// Check that `value` is within the declared bounds and, with `below`,
+    /// smaller than that parameter's value as given by `value_of`.
+    pub fn check(&self, value: i64, value_of: impl Fn(&str) -> i64) -> Result<(), String> {
+        let (max, limit) = match self.below {
+            Some(other) if value_of(other) <= self.max => (value_of(other) - 1, format!(" (below {})", other)),
+            _ => (self.max, String::new()),
+        };
+        if (self.min..=max).contains(&value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "parameter {} must be between {} and {}{}, got {}",
+                self.name, self.min, max, limit, value
+            ))
+        }
+    }
+}
+
+/// The metadata file of an input: `day08_example.toml` for
+/// `day08_example.txt`.
+pub fn metadata_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("toml")
+}
+
+/// Read `name = value` lines from an input's metadata file. A missing file
+/// has no parameters.
+///
+/// Only integer values are supported; `#` starts a comment line.
+pub fn read_metadata(path: &Path) -> Vec<(String, i64)> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => panic!("Failed to read {}: {}", path.display(), e),
+    };
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            parse_assignment(line).unwrap_or_else(|e| panic!("Invalid line '{}' in {}: {}", line, path.display(), e))
+        })
+        .collect()
+}
+
+/// Parse a `name=value` assignment, as given to `-p` or in a metadata file.
+pub fn parse_assignment(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got '{}'", s))?;
+    let value = value.trim();
+    let value = value
+        .parse()
+        .map_err(|_| format!("expected an integer value, got '{}'", value))?;
+    Ok((name.trim().to_string(), value))
+}
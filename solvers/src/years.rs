@@ -0,0 +1,53 @@
+// AUTO-GENERATED by build.rs. Do not edit manually.
+use crate::{input::Input, Param, Variant};
+
+pub const YEARS: &[u32] = &[2025];
+
+pub fn days(year: u32) -> &'static [u32] {
+    match year {
+        2025 => crate::days::DAYS,
+        _ => &[],
+    }
+}
+
+pub fn title(year: u32, day: u32) -> Option<&'static str> {
+    match year {
+        2025 => crate::days::title(day),
+        _ => None,
+    }
+}
+
+pub fn part_doc(year: u32, day: u32, part: u8) -> Option<&'static str> {
+    match year {
+        2025 => crate::days::part_doc(day, part),
+        _ => None,
+    }
+}
+
+pub fn params(year: u32, day: u32) -> &'static [Param] {
+    match year {
+        2025 => crate::days::params(day),
+        _ => &[],
+    }
+}
+
+pub fn variants(year: u32, day: u32) -> &'static [Variant] {
+    match year {
+        2025 => crate::days::variants(day),
+        _ => &[],
+    }
+}
+
+pub fn run_day(year: u32, day: u32, variant: Option<&str>, part: u8, input: &Input) -> Option<String> {
+    match year {
+        2025 => crate::days::run_day(day, variant, part, input),
+        _ => None,
+    }
+}
+
+pub fn parse_day(year: u32, day: u32, variant: Option<&str>, part: u8, input: &Input) -> bool {
+    match year {
+        2025 => crate::days::parse_day(day, variant, part, input),
+        _ => false,
+    }
+}